@@ -0,0 +1,3 @@
+-- sqlite
+-- allows paging through the events of all chores in time order
+create index idx_events_timestamp on events (timestamp);
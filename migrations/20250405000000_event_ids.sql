@@ -0,0 +1,24 @@
+-- sqlite
+-- give every event a stable ID so that individual events can be edited or
+-- deleted. Existing events keep their row IDs as their new IDs
+create table events_with_ids (
+    id integer not null primary key autoincrement,
+    -- which chore the event happened for
+    chore_id integer not null,
+    -- the time the event occurred, in a zone-aware datetime format
+    timestamp text not null,
+    foreign key (chore_id) references chores (id) on delete cascade
+);
+
+insert into events_with_ids (id, chore_id, timestamp)
+select rowid, chore_id, timestamp
+from events;
+
+drop table events;
+alter table events_with_ids rename to events;
+
+create index idx_events_chore_id_timestamp on events (chore_id, timestamp);
+create index idx_events_timestamp on events (timestamp);
+
+-- remember the ID of undone events so that redoing them restores the same ID
+alter table redo_events add column event_id integer;
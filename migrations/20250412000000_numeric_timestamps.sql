@@ -0,0 +1,52 @@
+-- sqlite
+-- store event times as milliseconds since the Unix epoch so that they sort
+-- correctly regardless of their UTC offset, keeping the time zone that the
+-- event was recorded in alongside
+create table events_numeric (
+    id integer not null primary key autoincrement,
+    -- which chore the event happened for
+    chore_id integer not null,
+    -- the instant the event occurred, in milliseconds since the Unix epoch
+    timestamp integer not null,
+    -- the IANA time zone (or fixed offset) the event was recorded in
+    time_zone text not null,
+    foreign key (chore_id) references chores (id) on delete cascade
+);
+
+-- the old timestamps look like `2025-03-16T20:24:36.123-06:00[America/Edmonton]`
+insert into events_numeric (id, chore_id, timestamp, time_zone)
+select
+    id,
+    chore_id,
+    cast(round((julianday(substr(timestamp, 1, instr(timestamp, '[') - 1)) - 2440587.5) * 86400000) as integer),
+    substr(timestamp, instr(timestamp, '[') + 1, length(timestamp) - instr(timestamp, '[') - 1)
+from events;
+
+drop table events;
+alter table events_numeric rename to events;
+
+create index idx_events_chore_id_timestamp on events (chore_id, timestamp);
+create index idx_events_timestamp on events (timestamp);
+
+create table redo_events_numeric (
+    -- the ID the event had before it was undone
+    event_id integer,
+    -- which chore the event happened for
+    chore_id integer not null,
+    -- the instant the event occurred, in milliseconds since the Unix epoch
+    timestamp integer not null,
+    -- the IANA time zone (or fixed offset) the event was recorded in
+    time_zone text not null,
+    foreign key (chore_id) references chores (id) on delete cascade
+);
+
+insert into redo_events_numeric (event_id, chore_id, timestamp, time_zone)
+select
+    event_id,
+    chore_id,
+    cast(round((julianday(substr(timestamp, 1, instr(timestamp, '[') - 1)) - 2440587.5) * 86400000) as integer),
+    substr(timestamp, instr(timestamp, '[') + 1, length(timestamp) - instr(timestamp, '[') - 1)
+from redo_events;
+
+drop table redo_events;
+alter table redo_events_numeric rename to redo_events;
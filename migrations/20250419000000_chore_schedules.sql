@@ -0,0 +1,4 @@
+-- sqlite
+-- an optional calendar schedule for the chore (see `crate::schedule::Schedule`),
+-- when empty the chore is due one interval after it was last completed
+alter table chores add column schedule text not null default '';
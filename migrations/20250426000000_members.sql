@@ -0,0 +1,13 @@
+-- sqlite
+-- the people in the household that chores can be attributed to
+create table members (
+    id integer not null primary key autoincrement,
+    name text not null
+);
+
+-- who completed the chore, if anyone was picked. Deleting a member keeps
+-- their events but forgets who did them
+alter table events add column member_id integer references members (id) on delete set null;
+alter table redo_events add column member_id integer references members (id) on delete set null;
+
+create index idx_events_member_id on events (member_id);
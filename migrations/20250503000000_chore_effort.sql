@@ -0,0 +1,4 @@
+-- sqlite
+-- how much work a chore is compared to other chores, used to weigh
+-- completions when comparing how much each member has done
+alter table chores add column effort integer not null default 1;
@@ -0,0 +1,9 @@
+-- sqlite
+-- events are either completions or postponements. Snoozing and skipping a
+-- chore move its next due date to `until` without counting as a completion
+alter table events add column kind text not null default 'completed';
+-- when a postponed chore is next due, in the same time zone as `timestamp`
+alter table events add column until integer;
+
+alter table redo_events add column kind text not null default 'completed';
+alter table redo_events add column until integer;
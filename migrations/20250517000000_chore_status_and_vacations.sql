@@ -0,0 +1,14 @@
+-- sqlite
+-- paused and archived chores are hidden from the home page but keep their
+-- events, one of 'active', 'paused' or 'archived'
+alter table chores add column status text not null default 'active';
+
+-- periods during which the whole household is away and due dates stop
+-- accruing. A vacation without an end lasts until it is ended
+create table vacations (
+    id integer not null primary key autoincrement,
+    -- epoch milliseconds
+    starts_at integer not null,
+    ends_at integer,
+    time_zone text not null
+);
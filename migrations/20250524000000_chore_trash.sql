@@ -0,0 +1,4 @@
+-- sqlite
+-- when the chore was moved to the trash, in milliseconds since the Unix epoch.
+-- Trashed chores keep their events until they are purged
+alter table chores add column deleted_at integer;
@@ -0,0 +1,4 @@
+-- sqlite
+-- which room of the home a chore belongs to, used to group and filter the
+-- home page. Chores without a room are shown on their own
+alter table chores add column room text;
@@ -0,0 +1,5 @@
+-- sqlite
+-- an optional emoji or built-in icon name, and an accent colour in #rrggbb
+-- form, to help tell chore buttons apart at a glance
+alter table chores add column icon text;
+alter table chores add column colour text;
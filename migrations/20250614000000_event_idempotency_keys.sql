@@ -0,0 +1,6 @@
+-- sqlite
+-- a key chosen by the client for completions it may send more than once,
+-- such as ones queued while offline, so that replaying them is harmless
+alter table events add column idempotency_key text;
+create unique index events_idempotency_key on events (idempotency_key)
+where idempotency_key is not null;
@@ -0,0 +1,21 @@
+-- sqlite
+-- people who can log in. Passwords are stored as Argon2 PHC strings
+create table users (
+    id integer not null primary key autoincrement,
+    username text not null unique collate nocase,
+    password_hash text not null,
+    -- epoch milliseconds
+    created_at integer not null
+);
+
+-- logged in browsers. Only the SHA-256 hash of a session's token is kept so
+-- that a copy of the database can't be used to log in
+create table sessions (
+    token_hash text not null primary key,
+    user_id integer not null references users (id) on delete cascade,
+    -- epoch milliseconds
+    created_at integer not null,
+    expires_at integer not null
+);
+
+create index idx_sessions_user_id on sessions (user_id);
@@ -0,0 +1,5 @@
+-- sqlite
+-- what each user is allowed to do: admins can do anything, members can
+-- record chores and viewers can only look. Everyone created so far was
+-- created as an admin
+alter table users add column role text not null default 'admin' check (role in ('admin', 'member', 'viewer'));
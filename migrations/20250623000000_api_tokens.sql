@@ -0,0 +1,15 @@
+-- sqlite
+-- long-lived tokens for scripts and home automation, sent as
+-- `Authorization: Bearer`. Like sessions, only the SHA-256 hash of a token
+-- is kept
+create table api_tokens (
+    id integer not null primary key autoincrement,
+    name text not null,
+    token_hash text not null unique,
+    -- space separated, e.g. 'chores:read events:write'
+    scopes text not null,
+    -- epoch milliseconds
+    created_at integer not null,
+    last_used_at integer,
+    revoked_at integer
+);
@@ -0,0 +1,4 @@
+-- sqlite
+-- which user recorded each event, as well as which member did the chore
+alter table events add column user_id integer references users (id) on delete set null;
+alter table redo_events add column user_id integer references users (id) on delete set null;
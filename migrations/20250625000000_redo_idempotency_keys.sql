@@ -0,0 +1,4 @@
+-- sqlite
+-- undone events keep their idempotency key, so that replaying a completion
+-- after it was undone and redone doesn't record it twice
+alter table redo_events add column idempotency_key text;
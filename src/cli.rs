@@ -1,4 +1,9 @@
-use clap::{ColorChoice, Parser};
+use axum::http::HeaderName;
+use clap::{ColorChoice, Parser, Subcommand};
+use ipnet::IpNet;
+use jiff::Span;
+
+use crate::db::{Role, Scope};
 use std::{
     net::{SocketAddr, ToSocketAddrs},
     path::PathBuf,
@@ -35,6 +40,80 @@ pub struct Cli {
     ///
     /// This file will be created if it does not exist
     pub sqlite_db: PathBuf,
+
+    #[arg(short, long, env, default_value = "30d", value_parser = parse_retention)]
+    /// How long deleted chores stay in the trash before they are purged
+    ///
+    /// Uses the same format as chore intervals, e.g. `2w` or `P30D`. Use `0d`
+    /// to keep deleted chores until they are purged by hand
+    pub trash_retention: Span,
+
+    #[arg(long, env)]
+    /// Send the session cookie over plain HTTP as well as HTTPS
+    ///
+    /// Only use this when chordle isn't behind HTTPS, such as on a LAN
+    pub insecure_cookies: bool,
+
+    #[arg(long, env, requires = "trusted_proxies")]
+    /// Trust a reverse proxy to say who is logged in with this header, such
+    /// as `Remote-User`
+    ///
+    /// Users who don't exist yet are created as members. The header is only
+    /// trusted from `--trusted-proxies`
+    pub trusted_proxy_header: Option<HeaderName>,
+
+    #[arg(long, env, value_delimiter = ',', requires = "trusted_proxy_header")]
+    /// Where the reverse proxy connects from, as comma-separated CIDRs such
+    /// as `10.0.0.0/8` or `::1/128`
+    pub trusted_proxies: Vec<IpNet>,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Create an admin who can log in, then exit
+    ///
+    /// The password is asked for on the terminal, or read from stdin. Once a
+    /// user exists, everyone has to log in to use chordle
+    CreateAdmin {
+        /// The name to log in with
+        username: String,
+    },
+    /// Create a user with a given role who can log in, then exit
+    ///
+    /// Admins can do anything, members can record chores and viewers can
+    /// only look
+    CreateUser {
+        /// The name to log in with
+        username: String,
+
+        #[arg(short, long, default_value = "member", value_parser = parse_role)]
+        /// What the user is allowed to do: admin, member or viewer
+        role: Role,
+    },
+    /// Create an API token for scripts and home automation, print it, then
+    /// exit
+    ///
+    /// The token is sent as `Authorization: Bearer <TOKEN>` and can't be
+    /// shown again
+    CreateToken {
+        /// What the token is for, to tell tokens apart
+        name: String,
+
+        #[arg(short, long = "scope", required = true, value_parser = parse_scope)]
+        /// What the token is allowed to do: chores:read, chores:write or
+        /// events:write. Can be given more than once
+        scopes: Vec<Scope>,
+    },
+    /// List API tokens, then exit
+    ListTokens,
+    /// Revoke an API token so it stops working, then exit
+    RevokeToken {
+        /// The token's ID, as shown by `list-tokens`
+        id: i64,
+    },
 }
 
 pub fn cli() -> Cli {
@@ -47,3 +126,21 @@ fn parse_socket_addr(s: &str) -> Result<SocketAddr, String> {
         .next()
         .ok_or_else(|| format!("{}: no addresses found", s))
 }
+
+fn parse_retention(s: &str) -> Result<Span, String> {
+    let span: Span = s.parse().map_err(|e: jiff::Error| e.to_string())?;
+    if span.is_negative() {
+        return Err(format!("{s}: retention must not be negative"));
+    }
+    Ok(span)
+}
+
+fn parse_role(s: &str) -> Result<Role, String> {
+    s.parse()
+        .map_err(|e: color_eyre::eyre::Error| e.to_string())
+}
+
+fn parse_scope(s: &str) -> Result<Scope, String> {
+    s.parse()
+        .map_err(|e: color_eyre::eyre::Error| e.to_string())
+}
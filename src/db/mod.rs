@@ -1,11 +1,17 @@
 use color_eyre::{Result, eyre::Context};
-use jiff::{Span, Zoned};
+use jiff::{Timestamp, Zoned};
 use sqlx::{SqlitePool, sqlite::SqliteConnectOptions};
 use std::path::Path;
+
+use crate::schedule::Pause;
 use types::DbChore;
 
 mod types;
-pub use types::{Chore, ChoreEvent, ChoreId, Event};
+pub use types::{
+    ApiToken, Chore, ChoreEvent, ChoreId, ChoreStatus, Event, EventCursor, EventFilter, EventId,
+    EventKind, EventOrder, EventPage, Member, MemberId, Role, Scope, TrashedChore, User, UserId,
+    Vacation,
+};
 
 #[derive(Clone, Debug)]
 pub struct Db {
@@ -45,9 +51,9 @@ impl Db {
         let db_chore = sqlx::query_as!(
             types::DbChore,
             r#"
-select id, name, interval
+select id, name, interval, schedule, effort, status, room, icon, colour
 from chores
-where id = ?
+where id = ? and deleted_at is null
             "#,
             dbid,
         )
@@ -62,17 +68,24 @@ where id = ?
         }
     }
 
-    pub async fn create_chore(&self, name: &str, interval: Span) -> Result<ChoreId> {
-        let interval = interval.to_string();
+    /// Create a new chore, returning its ID. The ID of `chore` is ignored
+    pub async fn create_chore(&self, chore: Chore) -> Result<ChoreId> {
+        let db_chore: DbChore = chore.into();
 
         let id: i64 = sqlx::query_scalar!(
             r#"
-insert into chores (name, interval)
-values (?, ?)
+insert into chores (name, interval, schedule, effort, status, room, icon, colour)
+values (?, ?, ?, ?, ?, ?, ?, ?)
 returning id
             "#,
-            name,
-            interval,
+            db_chore.name,
+            db_chore.interval,
+            db_chore.schedule,
+            db_chore.effort,
+            db_chore.status,
+            db_chore.room,
+            db_chore.icon,
+            db_chore.colour,
         )
         .fetch_one(&self.pool)
         .await
@@ -81,49 +94,140 @@ returning id
         Ok(id.into())
     }
 
-    pub async fn update_chore(&self, chore: Chore) -> Result<()> {
+    /// Update everything about a chore but its ID, returning `false` if no
+    /// chore with the given ID exists
+    pub async fn update_chore(&self, chore: Chore) -> Result<bool> {
         let db_chore: DbChore = chore.into();
 
-        sqlx::query!(
+        let result = sqlx::query!(
             r#"
 update chores
-set name = ?, interval = ?
-where id = ?
+set name = ?, interval = ?, schedule = ?, effort = ?, status = ?, room = ?, icon = ?,
+    colour = ?
+where id = ? and deleted_at is null
             "#,
             db_chore.name,
             db_chore.interval,
+            db_chore.schedule,
+            db_chore.effort,
+            db_chore.status,
+            db_chore.room,
+            db_chore.icon,
+            db_chore.colour,
             db_chore.id,
         )
         .execute(&self.pool)
         .await
         .wrap_err("Failed to update chore")?;
 
-        Ok(())
+        Ok(result.rows_affected() > 0)
     }
 
-    pub async fn delete_chore(&self, id: ChoreId) -> Result<()> {
+    /// Move a chore to the trash, keeping its events until it is purged.
+    /// Returns `false` if no chore with the given ID exists
+    pub async fn delete_chore(&self, id: ChoreId) -> Result<bool> {
         let dbid: i64 = id.into();
+        let now = Timestamp::now().as_millisecond();
 
-        sqlx::query!(
+        let result = sqlx::query!(
             r#"
-delete from chores
-where id = ?
+update chores
+set deleted_at = ?
+where id = ? and deleted_at is null
             "#,
+            now,
             dbid,
         )
         .execute(&self.pool)
         .await
         .wrap_err("Failed to delete chore")?;
 
-        Ok(())
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Take a chore back out of the trash, returning `false` if no trashed
+    /// chore with the given ID exists
+    pub async fn restore_chore(&self, id: ChoreId) -> Result<bool> {
+        let dbid: i64 = id.into();
+
+        let result = sqlx::query!(
+            r#"
+update chores
+set deleted_at = null
+where id = ? and deleted_at is not null
+            "#,
+            dbid,
+        )
+        .execute(&self.pool)
+        .await
+        .wrap_err("Failed to restore chore")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Permanently delete a trashed chore and all of its events, returning
+    /// `false` if no trashed chore with the given ID exists
+    pub async fn purge_chore(&self, id: ChoreId) -> Result<bool> {
+        let dbid: i64 = id.into();
+
+        let result = sqlx::query!(
+            r#"
+delete from chores
+where id = ? and deleted_at is not null
+            "#,
+            dbid,
+        )
+        .execute(&self.pool)
+        .await
+        .wrap_err("Failed to purge chore")?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Permanently delete every chore that was trashed before `before`,
+    /// returning how many were purged
+    pub async fn purge_chores_deleted_before(&self, before: Timestamp) -> Result<u64> {
+        let before = before.as_millisecond();
+
+        let result = sqlx::query!(
+            r#"
+delete from chores
+where deleted_at is not null and deleted_at < ?
+            "#,
+            before,
+        )
+        .execute(&self.pool)
+        .await
+        .wrap_err("Failed to purge trashed chores")?;
+
+        Ok(result.rows_affected())
+    }
+
+    /// Chores in the trash, most recently deleted first
+    pub async fn get_trashed_chores(&self) -> Result<Vec<TrashedChore>> {
+        let chores = sqlx::query_as!(
+            types::DbTrashedChore,
+            r#"
+select id, name, interval, schedule, effort, status, room, icon, colour, deleted_at as "deleted_at!"
+from chores
+where deleted_at is not null
+order by deleted_at desc
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await
+        .wrap_err("Failed to get trashed chores")?;
+
+        chores.into_iter().map(|chore| chore.try_into()).collect()
     }
 
     pub async fn get_all_chores(&self) -> Result<Vec<Chore>> {
         let chores = sqlx::query_as!(
             types::DbChore,
             r#"
-select id, name, interval
+select id, name, interval, schedule, effort, status, room, icon, colour
 from chores
+where deleted_at is null
 order by name asc
             "#
         )
@@ -134,6 +238,7 @@ order by name asc
         chores.into_iter().map(|chore| chore.try_into()).collect()
     }
 
+    /// Get every active chore along with the time it was last completed
     pub async fn get_all_chore_events(&self) -> Result<Vec<ChoreEvent>> {
         // assert times in the query, see
         // https://docs.rs/sqlx/0.8.3/sqlx/macro.query_as.html#troubleshooting-error-mismatched-types
@@ -145,73 +250,297 @@ select
     chores.id as "id!",
     chores.name as "name!",
     chores.interval as "interval!",
-    events.timestamp
+    chores.schedule as "schedule!",
+    chores.room,
+    chores.icon,
+    chores.colour,
+    events.timestamp as "timestamp?: i64",
+    events.time_zone as "time_zone?: String",
+    postponements.until as "postponed_until?: i64",
+    postponements.time_zone as "postponed_time_zone?: String"
 from
     chores
 left join
+    -- sqlite takes bare columns from the row that max() picked
     (select
         chore_id,
-        max(timestamp) as timestamp
+        max(timestamp) as timestamp,
+        time_zone
      from
         events
+     where
+        kind = 'completed'
      group by
         chore_id) as events
 on chores.id = events.chore_id
+left join
+    (select
+        chore_id,
+        max(timestamp) as timestamp,
+        until,
+        time_zone
+     from
+        events
+     where
+        kind != 'completed'
+     group by
+        chore_id) as postponements
+on chores.id = postponements.chore_id
+    -- completing a chore cancels any earlier postponement
+    and (events.timestamp is null or postponements.timestamp >= events.timestamp)
+where chores.status = 'active' and chores.deleted_at is null
 "#
         )
         .fetch_all(&self.pool)
         .await
         .wrap_err("Failed to get all chores")?;
 
-        chores.into_iter().map(|chore| chore.try_into()).collect()
+        let pauses = self.get_pauses().await?;
+        chores
+            .into_iter()
+            .map(|chore| {
+                let mut chore: ChoreEvent = chore.try_into()?;
+                chore.pauses = pauses.clone();
+                Ok(chore)
+            })
+            .collect()
     }
 
-    pub async fn record_chore_event(&self, chore_id: ChoreId) -> Result<()> {
-        let dbid: i64 = chore_id.into();
-        let timestamp = Zoned::now().to_string();
+    /// The rooms chores have been put in, in alphabetical order
+    pub async fn get_rooms(&self) -> Result<Vec<String>> {
+        let rooms = sqlx::query_scalar!(
+            r#"
+select distinct room as "room!"
+from chores
+where room is not null and deleted_at is null
+order by room asc
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await
+        .wrap_err("Failed to get rooms")?;
 
-        sqlx::query!(
+        Ok(rooms)
+    }
+
+    /// Get a single chore along with the time it was last completed
+    pub async fn get_chore_event(&self, id: ChoreId) -> Result<Option<ChoreEvent>> {
+        let dbid: i64 = id.into();
+
+        let chore = sqlx::query_as!(
+            types::DbChoreEvent,
             r#"
-insert into events (chore_id, timestamp)
-values (?, ?)
-            "#,
+select
+    chores.id as "id!",
+    chores.name as "name!",
+    chores.interval as "interval!",
+    chores.schedule as "schedule!",
+    chores.room,
+    chores.icon,
+    chores.colour,
+    events.timestamp as "timestamp?: i64",
+    events.time_zone as "time_zone?: String",
+    postponements.until as "postponed_until?: i64",
+    postponements.time_zone as "postponed_time_zone?: String"
+from
+    chores
+left join
+    (select
+        chore_id,
+        max(timestamp) as timestamp,
+        time_zone
+     from
+        events
+     where
+        chore_id = ? and kind = 'completed'
+     group by
+        chore_id) as events
+on chores.id = events.chore_id
+left join
+    (select
+        chore_id,
+        max(timestamp) as timestamp,
+        until,
+        time_zone
+     from
+        events
+     where
+        chore_id = ? and kind != 'completed'
+     group by
+        chore_id) as postponements
+on chores.id = postponements.chore_id
+    and (events.timestamp is null or postponements.timestamp >= events.timestamp)
+where chores.id = ? and chores.deleted_at is null
+"#,
             dbid,
+            dbid,
+            dbid,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .wrap_err_with(|| format!("Failed to get chore event for chore {dbid}"))?;
+
+        let Some(chore) = chore else {
+            return Ok(None);
+        };
+        let mut chore: ChoreEvent = chore.try_into()?;
+        chore.pauses = self.get_pauses().await?;
+        Ok(Some(chore))
+    }
+
+    /// Record that a chore was completed right now, optionally by a member
+    /// and by whoever was logged in. Returns `None` if the chore doesn't exist
+    /// or is in the trash
+    pub async fn record_chore_event(
+        &self,
+        chore_id: ChoreId,
+        member_id: Option<MemberId>,
+        user_id: Option<UserId>,
+    ) -> Result<Option<Event>> {
+        self.record_chore_event_when(chore_id, Zoned::now(), member_id, user_id, None)
+            .await
+    }
+
+    /// Record that a chore was completed at the given time, optionally by a
+    /// member. If a completion was already recorded with the same
+    /// idempotency key, that one is returned instead of recording another.
+    /// Returns `None` if the chore doesn't exist or is in the trash
+    pub async fn record_chore_event_when(
+        &self,
+        chore_id: ChoreId,
+        timestamp: Zoned,
+        member_id: Option<MemberId>,
+        user_id: Option<UserId>,
+        idempotency_key: Option<&str>,
+    ) -> Result<Option<Event>> {
+        let event = Event {
+            id: EventId(0),
+            chore_id,
             timestamp,
+            member_id,
+            user_id,
+            kind: EventKind::Completed,
+            until: None,
+        };
+        let Some(idempotency_key) = idempotency_key else {
+            return self
+                .insert_event(event)
+                .await
+                .wrap_err("Failed to record chore event");
+        };
+
+        let db_event: types::DbEvent = event.into();
+        let id = sqlx::query_scalar!(
+            r#"
+insert into events (chore_id, timestamp, time_zone, member_id, user_id, kind, until, idempotency_key)
+select ?, ?, ?, ?, ?, ?, ?, ?
+where exists (select 1 from chores where id = ? and deleted_at is null)
+on conflict (idempotency_key) where idempotency_key is not null do nothing
+returning id
+            "#,
+            db_event.chore_id,
+            db_event.timestamp,
+            db_event.time_zone,
+            db_event.member_id,
+            db_event.user_id,
+            db_event.kind,
+            db_event.until,
+            idempotency_key,
+            db_event.chore_id,
         )
-        .execute(&self.pool)
+        .fetch_optional(&self.pool)
         .await
         .wrap_err("Failed to record chore event")?;
 
-        sqlx::query!(r#"delete from redo_events"#,)
-            .execute(&self.pool)
-            .await
-            .wrap_err("Failed to clear redo events")?;
+        match id {
+            Some(id) => {
+                sqlx::query!(r#"delete from redo_events"#,)
+                    .execute(&self.pool)
+                    .await
+                    .wrap_err("Failed to clear redo events")?;
+                Ok(Some(types::DbEvent { id, ..db_event }.try_into()?))
+            }
+            None => {
+                let existing = sqlx::query_as!(
+                    types::DbEvent,
+                    r#"
+select id, chore_id, timestamp, time_zone, member_id, user_id, kind, until
+from events
+where idempotency_key = ?
+                    "#,
+                    idempotency_key,
+                )
+                .fetch_optional(&self.pool)
+                .await
+                .wrap_err_with(|| {
+                    format!("Failed to get event with idempotency key {idempotency_key}")
+                })?;
+                existing.map(TryInto::try_into).transpose()
+            }
+        }
+    }
 
-        Ok(())
+    /// Record that a chore was snoozed or skipped right now, moving when it is
+    /// next due to `until`. Returns `None` if the chore doesn't exist or is in
+    /// the trash
+    pub async fn postpone_chore(
+        &self,
+        chore_id: ChoreId,
+        kind: EventKind,
+        until: Zoned,
+        member_id: Option<MemberId>,
+        user_id: Option<UserId>,
+    ) -> Result<Option<Event>> {
+        let timestamp = Zoned::now();
+        // `until` is stored in the same time zone as the event itself
+        let until = until.with_time_zone(timestamp.time_zone().clone());
+        self.insert_event(Event {
+            id: EventId(0),
+            chore_id,
+            timestamp,
+            member_id,
+            user_id,
+            kind,
+            until: Some(until),
+        })
+        .await
+        .wrap_err("Failed to record chore postponement")
     }
 
-    pub async fn record_chore_event_when(&self, chore_id: ChoreId, timestamp: Zoned) -> Result<()> {
-        let dbid: i64 = chore_id.into();
-        let timestamp = timestamp.to_string();
+    /// Insert a new event, ignoring its ID, and clear the redo stack. Nothing
+    /// is inserted for chores that don't exist or are in the trash
+    async fn insert_event(&self, event: Event) -> Result<Option<Event>> {
+        let db_event: types::DbEvent = event.into();
 
-        sqlx::query!(
+        let id = sqlx::query_scalar!(
             r#"
-insert into events (chore_id, timestamp)
-values (?, ?)
+insert into events (chore_id, timestamp, time_zone, member_id, user_id, kind, until)
+select ?, ?, ?, ?, ?, ?, ?
+where exists (select 1 from chores where id = ? and deleted_at is null)
+returning id
             "#,
-            dbid,
-            timestamp,
+            db_event.chore_id,
+            db_event.timestamp,
+            db_event.time_zone,
+            db_event.member_id,
+            db_event.user_id,
+            db_event.kind,
+            db_event.until,
+            db_event.chore_id,
         )
-        .execute(&self.pool)
+        .fetch_optional(&self.pool)
         .await
-        .wrap_err("Failed to record chore event")?;
+        .wrap_err("Failed to insert event")?;
+        let Some(id) = id else {
+            return Ok(None);
+        };
 
         sqlx::query!(r#"delete from redo_events"#,)
             .execute(&self.pool)
             .await
             .wrap_err("Failed to clear redo events")?;
 
-        Ok(())
+        Ok(Some(types::DbEvent { id, ..db_event }.try_into()?))
     }
 
     pub async fn can_undo_chore_event(&self) -> Result<bool> {
@@ -219,6 +548,8 @@ values (?, ?)
             r#"
 select count(*) as count
 from events
+join chores on chores.id = events.chore_id
+where chores.deleted_at is null
             "#
         )
         .fetch_one(&self.pool)
@@ -228,13 +559,18 @@ from events
         Ok(can_undo.count > 0)
     }
 
-    pub async fn undo_chore_event(&self) -> Result<bool> {
+    /// Remove the most recent event of a chore that isn't in the trash and move
+    /// it onto the redo stack, returning the removed event or `None` if there
+    /// was nothing to undo
+    pub async fn undo_chore_event(&self) -> Result<Option<Event>> {
         let most_recent_chore_event = sqlx::query_as!(
             types::DbEvent,
             r#"
-select chore_id, timestamp
+select events.id, chore_id, timestamp, time_zone, member_id, user_id, kind, until
 from events
-order by timestamp desc
+join chores on chores.id = events.chore_id
+where chores.deleted_at is null
+order by timestamp desc, events.id desc
 limit 1
             "#
         )
@@ -243,7 +579,7 @@ limit 1
         .wrap_err("Failed to get most recent chore event")?;
 
         if most_recent_chore_event.is_none() {
-            return Ok(false);
+            return Ok(None);
         }
         let most_recent_chore_event = most_recent_chore_event.unwrap();
 
@@ -253,25 +589,34 @@ limit 1
             .await
             .wrap_err("Failed to start transaction")?;
 
-        sqlx::query!(
+        // the key goes along, so replays of a redone completion are still
+        // recognised
+        let idempotency_key = sqlx::query_scalar!(
             r#"
 delete from events
-where chore_id = ? and timestamp = ?
+where id = ?
+returning idempotency_key
 "#,
-            most_recent_chore_event.chore_id,
-            most_recent_chore_event.timestamp,
+            most_recent_chore_event.id,
         )
-        .execute(&mut *transaction)
+        .fetch_one(&mut *transaction)
         .await
         .wrap_err("Failed to delete most recent chore event")?;
 
         sqlx::query!(
             r#"
-insert into redo_events (chore_id, timestamp)
-values (?, ?)
+insert into redo_events (event_id, chore_id, timestamp, time_zone, member_id, user_id, kind, until, idempotency_key)
+values (?, ?, ?, ?, ?, ?, ?, ?, ?)
 "#,
+            most_recent_chore_event.id,
             most_recent_chore_event.chore_id,
             most_recent_chore_event.timestamp,
+            most_recent_chore_event.time_zone,
+            most_recent_chore_event.member_id,
+            most_recent_chore_event.user_id,
+            most_recent_chore_event.kind,
+            most_recent_chore_event.until,
+            idempotency_key,
         )
         .execute(&mut *transaction)
         .await
@@ -282,7 +627,7 @@ values (?, ?)
             .await
             .wrap_err("Failed to commit undo transaction")?;
 
-        Ok(true)
+        Ok(Some(most_recent_chore_event.try_into()?))
     }
 
     pub async fn can_redo_chore_event(&self) -> Result<bool> {
@@ -290,6 +635,8 @@ values (?, ?)
             r#"
 select count(*) as count
 from redo_events
+join chores on chores.id = redo_events.chore_id
+where chores.deleted_at is null
             "#
         )
         .fetch_one(&self.pool)
@@ -299,12 +646,17 @@ from redo_events
         Ok(can_redo.count > 0)
     }
 
-    pub async fn redo_chore_event(&self) -> Result<bool> {
+    /// Move the most recent event on the redo stack of a chore that isn't in
+    /// the trash back into the events, returning the restored event or `None`
+    /// if there was nothing to redo
+    pub async fn redo_chore_event(&self) -> Result<Option<Event>> {
         let most_recent_redo_chore_event = sqlx::query_as!(
-            types::DbEvent,
+            types::DbRedoEvent,
             r#"
-select chore_id, timestamp
+select event_id, chore_id, timestamp, time_zone, member_id, user_id, kind, until, idempotency_key
 from redo_events
+join chores on chores.id = redo_events.chore_id
+where chores.deleted_at is null
 order by timestamp desc
 limit 1
             "#
@@ -314,7 +666,7 @@ limit 1
         .wrap_err("Failed to get most recent redo chore event")?;
 
         if most_recent_redo_chore_event.is_none() {
-            return Ok(false);
+            return Ok(None);
         }
         let most_recent_redo_chore_event = most_recent_redo_chore_event.unwrap();
 
@@ -336,15 +688,24 @@ where chore_id = ? and timestamp = ?
         .await
         .wrap_err("Failed to delete most recent redo chore event")?;
 
-        sqlx::query!(
+        // events undone before events had IDs get a fresh ID
+        let id: i64 = sqlx::query_scalar!(
             r#"
-insert into events (chore_id, timestamp)
-values (?, ?)
+insert into events (id, chore_id, timestamp, time_zone, member_id, user_id, kind, until, idempotency_key)
+values (?, ?, ?, ?, ?, ?, ?, ?, ?)
+returning id
 "#,
+            most_recent_redo_chore_event.event_id,
             most_recent_redo_chore_event.chore_id,
             most_recent_redo_chore_event.timestamp,
+            most_recent_redo_chore_event.time_zone,
+            most_recent_redo_chore_event.member_id,
+            most_recent_redo_chore_event.user_id,
+            most_recent_redo_chore_event.kind,
+            most_recent_redo_chore_event.until,
+            most_recent_redo_chore_event.idempotency_key,
         )
-        .execute(&mut *transaction)
+        .fetch_one(&mut *transaction)
         .await
         .wrap_err("Failed to record redo event")?;
 
@@ -353,7 +714,481 @@ values (?, ?)
             .await
             .wrap_err("Failed to commit redo transaction")?;
 
-        Ok(true)
+        let event = types::DbEvent {
+            id,
+            chore_id: most_recent_redo_chore_event.chore_id,
+            timestamp: most_recent_redo_chore_event.timestamp,
+            time_zone: most_recent_redo_chore_event.time_zone,
+            member_id: most_recent_redo_chore_event.member_id,
+            user_id: most_recent_redo_chore_event.user_id,
+            kind: most_recent_redo_chore_event.kind,
+            until: most_recent_redo_chore_event.until,
+        };
+        Ok(Some(event.try_into()?))
+    }
+
+    /// Every vacation, most recent first
+    pub async fn get_vacations(&self) -> Result<Vec<Vacation>> {
+        let vacations = sqlx::query_as!(
+            types::DbVacation,
+            r#"
+select id, starts_at, ends_at, time_zone
+from vacations
+order by starts_at desc, id desc
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await
+        .wrap_err("Failed to get vacations")?;
+
+        vacations
+            .into_iter()
+            .map(|vacation| vacation.try_into())
+            .collect()
+    }
+
+    /// Vacations as periods during which chores don't fall due
+    pub async fn get_pauses(&self) -> Result<Vec<Pause>> {
+        let now = Timestamp::now();
+        Ok(self
+            .get_vacations()
+            .await?
+            .iter()
+            .map(|vacation| vacation.as_pause(now))
+            .collect())
+    }
+
+    pub async fn create_vacation(&self, start: &Zoned, end: Option<&Zoned>) -> Result<i64> {
+        let (starts_at, time_zone) = types::to_db_timestamp(start);
+        let ends_at = end.map(|end| end.timestamp().as_millisecond());
+
+        let id: i64 = sqlx::query_scalar!(
+            r#"
+insert into vacations (starts_at, ends_at, time_zone)
+values (?, ?, ?)
+returning id
+            "#,
+            starts_at,
+            ends_at,
+            time_zone,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .wrap_err("Failed to create vacation")?;
+
+        Ok(id)
+    }
+
+    /// End a vacation at the given time, returning `false` if no vacation with
+    /// the given ID exists
+    pub async fn end_vacation(&self, id: i64, end: &Zoned) -> Result<bool> {
+        let ends_at = end.timestamp().as_millisecond();
+
+        let result = sqlx::query!(
+            r#"
+update vacations
+set ends_at = ?
+where id = ?
+            "#,
+            ends_at,
+            id,
+        )
+        .execute(&self.pool)
+        .await
+        .wrap_err_with(|| format!("Failed to end vacation {id}"))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Delete a vacation, returning `false` if no vacation with the given ID exists
+    pub async fn delete_vacation(&self, id: i64) -> Result<bool> {
+        let result = sqlx::query!(
+            r#"
+delete from vacations
+where id = ?
+            "#,
+            id,
+        )
+        .execute(&self.pool)
+        .await
+        .wrap_err_with(|| format!("Failed to delete vacation {id}"))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    pub async fn get_members(&self) -> Result<Vec<Member>> {
+        let members = sqlx::query_as!(
+            types::DbMember,
+            r#"
+select id, name
+from members
+order by name asc, id asc
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await
+        .wrap_err("Failed to get members")?;
+
+        Ok(members.into_iter().map(Member::from).collect())
+    }
+
+    pub async fn get_member(&self, id: MemberId) -> Result<Option<Member>> {
+        let dbid: i64 = id.into();
+
+        let member = sqlx::query_as!(
+            types::DbMember,
+            r#"
+select id, name
+from members
+where id = ?
+            "#,
+            dbid,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .wrap_err_with(|| format!("Failed to get member {dbid}"))?;
+
+        Ok(member.map(Member::from))
+    }
+
+    pub async fn create_member(&self, name: &str) -> Result<MemberId> {
+        let id: i64 = sqlx::query_scalar!(
+            r#"
+insert into members (name)
+values (?)
+returning id
+            "#,
+            name,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .wrap_err("Failed to create member")?;
+
+        Ok(id.into())
+    }
+
+    /// Rename a member, returning `false` if no member with the given ID exists
+    pub async fn update_member(&self, member: Member) -> Result<bool> {
+        let dbid: i64 = member.id.into();
+
+        let result = sqlx::query!(
+            r#"
+update members
+set name = ?
+where id = ?
+            "#,
+            member.name,
+            dbid,
+        )
+        .execute(&self.pool)
+        .await
+        .wrap_err_with(|| format!("Failed to update member {dbid}"))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Delete a member, returning `false` if no member with the given ID
+    /// exists. Their events are kept but no longer attributed to anyone
+    pub async fn delete_member(&self, id: MemberId) -> Result<bool> {
+        let dbid: i64 = id.into();
+
+        let result = sqlx::query!(
+            r#"
+delete from members
+where id = ?
+            "#,
+            dbid,
+        )
+        .execute(&self.pool)
+        .await
+        .wrap_err_with(|| format!("Failed to delete member {dbid}"))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// How many users there are. With none, chordle is open to everyone
+    pub async fn count_users(&self) -> Result<i64> {
+        let count = sqlx::query_scalar!(r#"select count(*) as "count!: i64" from users"#)
+            .fetch_one(&self.pool)
+            .await
+            .wrap_err("Failed to count users")?;
+
+        Ok(count)
+    }
+
+    /// Create a user with an already hashed password
+    pub async fn create_user(
+        &self,
+        username: &str,
+        password_hash: &str,
+        role: Role,
+    ) -> Result<UserId> {
+        let now = Timestamp::now().as_millisecond();
+        let role = role.as_str();
+        let id: i64 = sqlx::query_scalar!(
+            r#"
+insert into users (username, password_hash, created_at, role)
+values (?, ?, ?, ?)
+returning id
+            "#,
+            username,
+            password_hash,
+            now,
+            role,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .wrap_err_with(|| format!("Failed to create user {username}"))?;
+
+        Ok(id.into())
+    }
+
+    /// Find a user by name, ignoring case, creating them with a role and
+    /// password hash if nobody has that name yet
+    pub async fn get_or_create_user(
+        &self,
+        username: &str,
+        password_hash: &str,
+        role: Role,
+    ) -> Result<User> {
+        let now = Timestamp::now().as_millisecond();
+        let role = role.as_str();
+        sqlx::query!(
+            r#"
+insert into users (username, password_hash, created_at, role)
+values (?, ?, ?, ?)
+on conflict (username) do nothing
+            "#,
+            username,
+            password_hash,
+            now,
+            role,
+        )
+        .execute(&self.pool)
+        .await
+        .wrap_err_with(|| format!("Failed to create user {username}"))?;
+
+        let user = sqlx::query_as!(
+            types::DbUser,
+            r#"
+select id, username, role
+from users
+where username = ?
+            "#,
+            username,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .wrap_err_with(|| format!("Failed to get user {username}"))?;
+
+        user.try_into()
+    }
+
+    pub async fn get_users(&self) -> Result<Vec<User>> {
+        let users = sqlx::query_as!(
+            types::DbUser,
+            r#"
+select id, username, role
+from users
+order by username
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .wrap_err("Failed to get users")?;
+
+        users.into_iter().map(User::try_from).collect()
+    }
+
+    /// Find a user by name, ignoring case, along with their password hash
+    pub async fn get_user_for_login(&self, username: &str) -> Result<Option<(User, String)>> {
+        let user = sqlx::query!(
+            r#"
+select id, username, role, password_hash
+from users
+where username = ?
+            "#,
+            username,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .wrap_err_with(|| format!("Failed to get user {username}"))?;
+
+        user.map(|user| {
+            let db_user = types::DbUser {
+                id: user.id,
+                username: user.username,
+                role: user.role,
+            };
+            Ok((db_user.try_into()?, user.password_hash))
+        })
+        .transpose()
+    }
+
+    /// Start a session for a user, clearing out any sessions that have expired
+    pub async fn create_session(
+        &self,
+        user_id: UserId,
+        token_hash: &str,
+        expires_at: Timestamp,
+    ) -> Result<()> {
+        let dbid: i64 = user_id.into();
+        let now = Timestamp::now().as_millisecond();
+        let expires_at = expires_at.as_millisecond();
+
+        sqlx::query!(
+            r#"
+delete from sessions
+where expires_at <= ?
+            "#,
+            now,
+        )
+        .execute(&self.pool)
+        .await
+        .wrap_err("Failed to delete expired sessions")?;
+
+        sqlx::query!(
+            r#"
+insert into sessions (token_hash, user_id, created_at, expires_at)
+values (?, ?, ?, ?)
+            "#,
+            token_hash,
+            dbid,
+            now,
+            expires_at,
+        )
+        .execute(&self.pool)
+        .await
+        .wrap_err_with(|| format!("Failed to create session for user {dbid}"))?;
+
+        Ok(())
+    }
+
+    /// The user a session belongs to, if it exists and hasn't expired
+    pub async fn get_session_user(&self, token_hash: &str) -> Result<Option<User>> {
+        let now = Timestamp::now().as_millisecond();
+
+        let user = sqlx::query_as!(
+            types::DbUser,
+            r#"
+select users.id, users.username, users.role
+from sessions
+join users on users.id = sessions.user_id
+where sessions.token_hash = ? and sessions.expires_at > ?
+            "#,
+            token_hash,
+            now,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .wrap_err("Failed to get session")?;
+
+        user.map(User::try_from).transpose()
+    }
+
+    pub async fn delete_session(&self, token_hash: &str) -> Result<()> {
+        sqlx::query!(
+            r#"
+delete from sessions
+where token_hash = ?
+            "#,
+            token_hash,
+        )
+        .execute(&self.pool)
+        .await
+        .wrap_err("Failed to delete session")?;
+
+        Ok(())
+    }
+
+    /// Create an API token from the hash of its secret, returning its ID
+    pub async fn create_api_token(
+        &self,
+        name: &str,
+        token_hash: &str,
+        scopes: &[Scope],
+    ) -> Result<i64> {
+        let now = Timestamp::now().as_millisecond();
+        let scopes = scopes
+            .iter()
+            .map(Scope::as_str)
+            .collect::<Vec<_>>()
+            .join(" ");
+        let id = sqlx::query_scalar!(
+            r#"
+insert into api_tokens (name, token_hash, scopes, created_at)
+values (?, ?, ?, ?)
+returning id
+            "#,
+            name,
+            token_hash,
+            scopes,
+            now,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .wrap_err_with(|| format!("Failed to create API token {name}"))?;
+
+        Ok(id)
+    }
+
+    /// Every API token, including revoked ones, newest first
+    pub async fn get_api_tokens(&self) -> Result<Vec<ApiToken>> {
+        let tokens = sqlx::query_as!(
+            types::DbApiToken,
+            r#"
+select id, name, scopes, created_at, last_used_at, revoked_at
+from api_tokens
+order by created_at desc
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await
+        .wrap_err("Failed to get API tokens")?;
+
+        tokens.into_iter().map(ApiToken::try_from).collect()
+    }
+
+    /// Look up an unrevoked API token by the hash of its secret, noting that
+    /// it was just used
+    pub async fn use_api_token(&self, token_hash: &str) -> Result<Option<ApiToken>> {
+        let now = Timestamp::now().as_millisecond();
+        let token = sqlx::query_as!(
+            types::DbApiToken,
+            r#"
+update api_tokens
+set last_used_at = ?
+where token_hash = ? and revoked_at is null
+returning id, name, scopes, created_at, last_used_at, revoked_at
+            "#,
+            now,
+            token_hash,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .wrap_err("Failed to use API token")?;
+
+        token.map(ApiToken::try_from).transpose()
+    }
+
+    /// Stop an API token from working, returning whether there was an
+    /// unrevoked token with that ID
+    pub async fn revoke_api_token(&self, id: i64) -> Result<bool> {
+        let now = Timestamp::now().as_millisecond();
+        let result = sqlx::query!(
+            r#"
+update api_tokens
+set revoked_at = ?
+where id = ? and revoked_at is null
+            "#,
+            now,
+            id,
+        )
+        .execute(&self.pool)
+        .await
+        .wrap_err_with(|| format!("Failed to revoke API token {id}"))?;
+
+        Ok(result.rows_affected() > 0)
     }
 
     pub async fn get_chore_completions(&self, chore_id: ChoreId) -> Result<Vec<Event>> {
@@ -362,10 +1197,10 @@ values (?, ?)
         let events = sqlx::query_as!(
             types::DbEvent,
             r#"
-select chore_id, timestamp
+select id, chore_id, timestamp, time_zone, member_id, user_id, kind, until
 from events
-where chore_id = ?
-order by timestamp asc
+where chore_id = ? and kind = 'completed'
+order by timestamp asc, id asc
             "#,
             dbid,
         )
@@ -374,4 +1209,448 @@ order by timestamp asc
         .wrap_err_with(|| format!("Failed to get chore events for chore {dbid}"))?;
         events.into_iter().map(|event| event.try_into()).collect()
     }
+
+    /// Every completion of a chore that isn't in the trash between `since`
+    /// (inclusive) and `until` (exclusive), oldest first
+    pub async fn get_completions_between(
+        &self,
+        since: Timestamp,
+        until: Timestamp,
+    ) -> Result<Vec<Event>> {
+        let since = since.as_millisecond();
+        let until = until.as_millisecond();
+
+        let events = sqlx::query_as!(
+            types::DbEvent,
+            r#"
+select events.id, events.chore_id, events.timestamp, events.time_zone, events.member_id, events.user_id, events.kind, events.until
+from events
+join chores on chores.id = events.chore_id
+where events.kind = 'completed'
+    and events.timestamp >= ?
+    and events.timestamp < ?
+    and chores.deleted_at is null
+order by events.timestamp asc, events.id asc
+            "#,
+            since,
+            until,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .wrap_err("Failed to get completions")?;
+        events.into_iter().map(|event| event.try_into()).collect()
+    }
+
+    /// Every event of a chore including snoozes and skips, newest first
+    pub async fn get_chore_history(&self, chore_id: ChoreId) -> Result<Vec<Event>> {
+        let dbid: i64 = chore_id.into();
+
+        let events = sqlx::query_as!(
+            types::DbEvent,
+            r#"
+select id, chore_id, timestamp, time_zone, member_id, user_id, kind, until
+from events
+where chore_id = ?
+order by timestamp desc, id desc
+            "#,
+            dbid,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .wrap_err_with(|| format!("Failed to get history for chore {dbid}"))?;
+        events.into_iter().map(|event| event.try_into()).collect()
+    }
+
+    /// Count how many times a chore was snoozed and skipped
+    pub async fn count_chore_postponements(&self, chore_id: ChoreId) -> Result<(usize, usize)> {
+        let dbid: i64 = chore_id.into();
+
+        let counts = sqlx::query!(
+            r#"
+select
+    count(case when kind = 'snoozed' then 1 end) as "snoozed!: i64",
+    count(case when kind = 'skipped' then 1 end) as "skipped!: i64"
+from events
+where chore_id = ?
+            "#,
+            dbid,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .wrap_err_with(|| format!("Failed to count postponements for chore {dbid}"))?;
+
+        Ok((counts.snoozed as usize, counts.skipped as usize))
+    }
+
+    pub async fn get_event(&self, id: EventId) -> Result<Option<Event>> {
+        let dbid: i64 = id.into();
+
+        let event = sqlx::query_as!(
+            types::DbEvent,
+            r#"
+select id, chore_id, timestamp, time_zone, member_id, user_id, kind, until
+from events
+where id = ?
+            "#,
+            dbid,
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .wrap_err_with(|| format!("Failed to get event {dbid}"))?;
+
+        event.map(|event| event.try_into()).transpose()
+    }
+
+    /// Change when an event happened, who did it and until when it postponed
+    /// the chore, returning `false` if no event with the given ID exists
+    pub async fn update_event(&self, event: Event) -> Result<bool> {
+        let db_event: types::DbEvent = event.into();
+        let dbid = db_event.id;
+
+        let result = sqlx::query!(
+            r#"
+update events
+set timestamp = ?, time_zone = ?, member_id = ?, until = ?
+where id = ?
+            "#,
+            db_event.timestamp,
+            db_event.time_zone,
+            db_event.member_id,
+            db_event.until,
+            dbid,
+        )
+        .execute(&self.pool)
+        .await
+        .wrap_err_with(|| format!("Failed to update event {dbid}"))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Delete a single event, returning `false` if no event with the given ID
+    /// exists. Unlike undoing, the event can't be redone
+    pub async fn delete_event(&self, id: EventId) -> Result<bool> {
+        let dbid: i64 = id.into();
+
+        let result = sqlx::query!(
+            r#"
+delete from events
+where id = ?
+            "#,
+            dbid,
+        )
+        .execute(&self.pool)
+        .await
+        .wrap_err_with(|| format!("Failed to delete event {dbid}"))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// List a page of events matching the filter
+    pub async fn get_events(&self, filter: &EventFilter) -> Result<EventPage> {
+        // missing bounds are replaced with the earliest and latest possible
+        // times so that the indexes can always be used
+        let since = filter
+            .since
+            .map_or(i64::MIN, |since| since.as_millisecond());
+        let until = filter
+            .until
+            .map_or(i64::MAX, |until| until.as_millisecond());
+
+        // fetch one more event than asked for to know if there is another page
+        let limit = i64::from(filter.limit) + 1;
+
+        // with no cursor, start from the edge of the time range. The event ID
+        // makes `since` inclusive and `until` exclusive
+        let (from_timestamp, from_id) = match (&filter.cursor, filter.order) {
+            (Some(cursor), _) => (cursor.timestamp, cursor.id),
+            (None, EventOrder::Newest) => (until, i64::MIN),
+            (None, EventOrder::Oldest) => (since, i64::MIN),
+        };
+
+        let mut events = match (filter.chore_id, filter.order) {
+            (Some(chore_id), EventOrder::Newest) => {
+                let dbid: i64 = chore_id.into();
+                sqlx::query_as!(
+                    types::DbEvent,
+                    r#"
+select id, chore_id, timestamp, time_zone, member_id, user_id, kind, until
+from events
+where chore_id = ?
+    and timestamp >= ?
+    and (timestamp < ? or (timestamp = ? and id < ?))
+order by timestamp desc, id desc
+limit ?
+                    "#,
+                    dbid,
+                    since,
+                    from_timestamp,
+                    from_timestamp,
+                    from_id,
+                    limit,
+                )
+                .fetch_all(&self.pool)
+                .await
+            }
+            (Some(chore_id), EventOrder::Oldest) => {
+                let dbid: i64 = chore_id.into();
+                sqlx::query_as!(
+                    types::DbEvent,
+                    r#"
+select id, chore_id, timestamp, time_zone, member_id, user_id, kind, until
+from events
+where chore_id = ?
+    and timestamp < ?
+    and (timestamp > ? or (timestamp = ? and id > ?))
+order by timestamp asc, id asc
+limit ?
+                    "#,
+                    dbid,
+                    until,
+                    from_timestamp,
+                    from_timestamp,
+                    from_id,
+                    limit,
+                )
+                .fetch_all(&self.pool)
+                .await
+            }
+            (None, EventOrder::Newest) => {
+                sqlx::query_as!(
+                    types::DbEvent,
+                    r#"
+select id, chore_id, timestamp, time_zone, member_id, user_id, kind, until
+from events
+where timestamp >= ?
+    and (timestamp < ? or (timestamp = ? and id < ?))
+order by timestamp desc, id desc
+limit ?
+                    "#,
+                    since,
+                    from_timestamp,
+                    from_timestamp,
+                    from_id,
+                    limit,
+                )
+                .fetch_all(&self.pool)
+                .await
+            }
+            (None, EventOrder::Oldest) => {
+                sqlx::query_as!(
+                    types::DbEvent,
+                    r#"
+select id, chore_id, timestamp, time_zone, member_id, user_id, kind, until
+from events
+where timestamp < ?
+    and (timestamp > ? or (timestamp = ? and id > ?))
+order by timestamp asc, id asc
+limit ?
+                    "#,
+                    until,
+                    from_timestamp,
+                    from_timestamp,
+                    from_id,
+                    limit,
+                )
+                .fetch_all(&self.pool)
+                .await
+            }
+        }
+        .wrap_err("Failed to get page of events")?;
+
+        let next_cursor = if events.len() > filter.limit as usize {
+            events.truncate(filter.limit as usize);
+            events.last().map(|event| EventCursor {
+                timestamp: event.timestamp,
+                id: event.id,
+            })
+        } else {
+            None
+        };
+
+        let events = events
+            .into_iter()
+            .map(|event| event.try_into())
+            .collect::<Result<Vec<Event>>>()?;
+
+        Ok(EventPage {
+            events,
+            next_cursor,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jiff::ToSpan;
+
+    async fn create_chore(db: &Db, name: &str) -> ChoreId {
+        db.create_chore(Chore {
+            id: ChoreId::default(),
+            name: name.to_string(),
+            interval: 7.days(),
+            schedule: Default::default(),
+            effort: 1,
+            status: ChoreStatus::Active,
+            room: None,
+            icon: None,
+            colour: None,
+        })
+        .await
+        .unwrap()
+    }
+
+    #[sqlx::test]
+    async fn trashed_chores_are_restored_and_purged(pool: SqlitePool) {
+        let db = Db { pool };
+        let chore_id = create_chore(&db, "Dishes").await;
+        let event = db
+            .record_chore_event(chore_id, None, None)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert!(!db.restore_chore(chore_id).await.unwrap());
+        assert!(!db.purge_chore(chore_id).await.unwrap());
+        assert!(db.delete_chore(chore_id).await.unwrap());
+        assert!(db.get_chore(chore_id).await.unwrap().is_none());
+        assert_eq!(db.get_trashed_chores().await.unwrap().len(), 1);
+
+        assert!(db.restore_chore(chore_id).await.unwrap());
+        assert!(db.get_chore(chore_id).await.unwrap().is_some());
+        assert!(db.get_trashed_chores().await.unwrap().is_empty());
+        assert!(db.get_event(event.id).await.unwrap().is_some());
+
+        assert!(db.delete_chore(chore_id).await.unwrap());
+        assert!(db.purge_chore(chore_id).await.unwrap());
+        assert!(db.get_trashed_chores().await.unwrap().is_empty());
+        assert!(db.get_event(event.id).await.unwrap().is_none());
+
+        let chore_id = create_chore(&db, "Laundry").await;
+        assert!(db.delete_chore(chore_id).await.unwrap());
+        let an_hour_ago = Timestamp::now() - 1.hour();
+        assert_eq!(
+            db.purge_chores_deleted_before(an_hour_ago).await.unwrap(),
+            0
+        );
+        let soon = Timestamp::now() + 1.second();
+        assert_eq!(db.purge_chores_deleted_before(soon).await.unwrap(), 1);
+        assert!(db.get_trashed_chores().await.unwrap().is_empty());
+    }
+
+    #[sqlx::test]
+    async fn trashed_chores_cannot_be_recorded_undone_or_redone(pool: SqlitePool) {
+        let db = Db { pool };
+        let chore_id = create_chore(&db, "Dishes").await;
+        let event = db
+            .record_chore_event(chore_id, None, None)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(db.delete_chore(chore_id).await.unwrap());
+
+        assert!(
+            db.record_chore_event(chore_id, None, None)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        let tomorrow = &Zoned::now() + 1.day();
+        assert!(
+            db.postpone_chore(chore_id, EventKind::Snoozed, tomorrow, None, None)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(!db.can_undo_chore_event().await.unwrap());
+        assert!(db.undo_chore_event().await.unwrap().is_none());
+
+        assert!(db.restore_chore(chore_id).await.unwrap());
+        let undone = db.undo_chore_event().await.unwrap().unwrap();
+        assert_eq!(undone.id, event.id);
+        assert!(db.delete_chore(chore_id).await.unwrap());
+        assert!(!db.can_redo_chore_event().await.unwrap());
+        assert!(db.redo_chore_event().await.unwrap().is_none());
+
+        assert!(db.restore_chore(chore_id).await.unwrap());
+        let redone = db.redo_chore_event().await.unwrap().unwrap();
+        assert_eq!(redone.id, event.id);
+    }
+
+    #[sqlx::test]
+    async fn events_with_the_same_key_are_recorded_once(pool: SqlitePool) {
+        let db = Db { pool };
+        let chore_id = create_chore(&db, "Dishes").await;
+        let yesterday = &Zoned::now() - 1.day();
+
+        let first = db
+            .record_chore_event_when(chore_id, yesterday, None, None, Some("key"))
+            .await
+            .unwrap()
+            .unwrap();
+        let second = db
+            .record_chore_event_when(chore_id, Zoned::now(), None, None, Some("key"))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(first.id, second.id);
+        assert_eq!(second.timestamp, first.timestamp);
+
+        let other = db
+            .record_chore_event_when(chore_id, Zoned::now(), None, None, Some("other"))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_ne!(first.id, other.id);
+        let filter = EventFilter {
+            chore_id: Some(chore_id),
+            limit: 10,
+            ..Default::default()
+        };
+        assert_eq!(db.get_events(&filter).await.unwrap().events.len(), 2);
+    }
+
+    #[sqlx::test]
+    async fn events_keep_their_key_through_undo_and_redo(pool: SqlitePool) {
+        let db = Db { pool };
+        let chore_id = create_chore(&db, "Dishes").await;
+
+        let first = db
+            .record_chore_event_when(chore_id, Zoned::now(), None, None, Some("key"))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(db.undo_chore_event().await.unwrap().unwrap().id, first.id);
+        assert_eq!(db.redo_chore_event().await.unwrap().unwrap().id, first.id);
+
+        let replayed = db
+            .record_chore_event_when(chore_id, Zoned::now(), None, None, Some("key"))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(replayed.id, first.id);
+        let filter = EventFilter {
+            chore_id: Some(chore_id),
+            limit: 10,
+            ..Default::default()
+        };
+        assert_eq!(db.get_events(&filter).await.unwrap().events.len(), 1);
+    }
+
+    #[sqlx::test]
+    async fn missing_chores_cannot_be_recorded(pool: SqlitePool) {
+        let db = Db { pool };
+        assert!(
+            db.record_chore_event(ChoreId(1), None, None)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            db.record_chore_event_when(ChoreId(1), Zoned::now(), None, None, Some("key"))
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
 }
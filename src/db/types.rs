@@ -1,13 +1,16 @@
 use std::{
     fmt::Display,
     ops::{Deref, DerefMut},
+    str::FromStr,
 };
 
 use color_eyre::{Result, eyre::Context};
-use jiff::{Span, Zoned};
-use serde::{Deserialize, Serialize};
+use jiff::{Span, Timestamp, Zoned, civil::Date};
+use serde::{Deserialize, Serialize, Serializer};
 
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Copy)]
+use crate::schedule::{Pause, Schedule};
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Copy)]
 /// The ID of a chore
 pub struct ChoreId(pub i64);
 
@@ -51,6 +54,83 @@ pub struct Chore {
     pub name: String,
     /// The interval in which this chore should be done
     pub interval: Span,
+    /// Whether the chore is due a fixed interval after it was last done or
+    /// on fixed calendar dates
+    #[serde(default)]
+    pub schedule: Schedule,
+    /// How much work the chore is compared to other chores
+    #[serde(default = "default_effort")]
+    pub effort: u32,
+    #[serde(default)]
+    pub status: ChoreStatus,
+    /// Which room of the home the chore belongs to, if any
+    #[serde(default)]
+    pub room: Option<String>,
+    /// An emoji or the name of a built-in icon shown on the chore's button
+    #[serde(default)]
+    pub icon: Option<String>,
+    /// An accent colour for the chore's button, in `#rrggbb` form
+    #[serde(default)]
+    pub colour: Option<String>,
+}
+
+fn default_effort() -> u32 {
+    1
+}
+
+impl Chore {
+    /// When the chore is next due after being completed at `last_completed`,
+    /// not counting time the household was paused before `now`
+    pub fn next_due_after(
+        &self,
+        last_completed: &Zoned,
+        pauses: &[Pause],
+        now: Timestamp,
+    ) -> Zoned {
+        self.schedule
+            .next_due_with_pauses(self.interval, last_completed, pauses, now)
+    }
+}
+
+/// Whether a chore shows up on the home page
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ChoreStatus {
+    #[default]
+    Active,
+    /// Hidden for now but expected to come back
+    Paused,
+    /// Hidden for good, but its history is kept
+    Archived,
+}
+
+impl ChoreStatus {
+    pub const ALL: [ChoreStatus; 3] = [
+        ChoreStatus::Active,
+        ChoreStatus::Paused,
+        ChoreStatus::Archived,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChoreStatus::Active => "active",
+            ChoreStatus::Paused => "paused",
+            ChoreStatus::Archived => "archived",
+        }
+    }
+}
+
+impl FromStr for ChoreStatus {
+    type Err = color_eyre::eyre::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "active" => Ok(ChoreStatus::Active),
+            "paused" => Ok(ChoreStatus::Paused),
+            "archived" => Ok(ChoreStatus::Archived),
+            _ => Err(color_eyre::eyre::eyre!("Unknown chore status '{s}'")),
+        }
+    }
 }
 
 impl AsRef<Chore> for Chore {
@@ -63,6 +143,12 @@ pub struct DbChore {
     pub id: DbChoreId,
     pub name: String,
     pub interval: String,
+    pub schedule: String,
+    pub effort: i64,
+    pub status: String,
+    pub room: Option<String>,
+    pub icon: Option<String>,
+    pub colour: Option<String>,
 }
 impl From<Chore> for DbChore {
     fn from(chore: Chore) -> Self {
@@ -70,6 +156,12 @@ impl From<Chore> for DbChore {
             id: chore.id.into(),
             name: chore.name,
             interval: chore.interval.to_string(),
+            schedule: chore.schedule.to_string(),
+            effort: chore.effort.into(),
+            status: chore.status.as_str().to_string(),
+            room: chore.room,
+            icon: chore.icon,
+            colour: chore.colour,
         }
     }
 }
@@ -87,14 +179,404 @@ impl TryFrom<DbChore> for Chore {
                     interval = chore.interval
                 )
             })?,
+            schedule: chore.schedule.parse().wrap_err_with(|| {
+                format!(
+                    "Failed to parse schedule '{schedule}' for chore {id}",
+                    id = chore.id,
+                    schedule = chore.schedule
+                )
+            })?,
+            effort: u32::try_from(chore.effort).wrap_err_with(|| {
+                format!(
+                    "Invalid effort {effort} for chore {id}",
+                    id = chore.id,
+                    effort = chore.effort
+                )
+            })?,
+            status: chore
+                .status
+                .parse()
+                .wrap_err_with(|| format!("Failed to parse status of chore {id}", id = chore.id))?,
+            room: chore.room,
+            icon: chore.icon,
+            colour: chore.colour,
+        })
+    }
+}
+
+/// A chore in the trash, waiting to be restored or purged
+#[derive(Debug, Clone)]
+pub struct TrashedChore {
+    pub chore: Chore,
+    pub deleted_at: Timestamp,
+}
+
+pub struct DbTrashedChore {
+    pub id: DbChoreId,
+    pub name: String,
+    pub interval: String,
+    pub schedule: String,
+    pub effort: i64,
+    pub status: String,
+    pub room: Option<String>,
+    pub icon: Option<String>,
+    pub colour: Option<String>,
+    pub deleted_at: DbTimestamp,
+}
+
+impl TryFrom<DbTrashedChore> for TrashedChore {
+    type Error = color_eyre::eyre::Error;
+
+    fn try_from(chore: DbTrashedChore) -> Result<Self> {
+        let deleted_at = Timestamp::from_millisecond(chore.deleted_at)
+            .wrap_err_with(|| format!("Invalid deletion time for chore {id}", id = chore.id))?;
+        let chore = DbChore {
+            id: chore.id,
+            name: chore.name,
+            interval: chore.interval,
+            schedule: chore.schedule,
+            effort: chore.effort,
+            status: chore.status,
+            room: chore.room,
+            icon: chore.icon,
+            colour: chore.colour,
+        };
+        Ok(Self {
+            chore: chore.try_into()?,
+            deleted_at,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Copy)]
+/// The ID of an event
+pub struct EventId(pub i64);
+
+impl Deref for EventId {
+    type Target = i64;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Display for EventId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub type DbEventId = i64;
+impl From<DbEventId> for EventId {
+    fn from(id: DbEventId) -> Self {
+        Self(id)
+    }
+}
+impl From<EventId> for DbEventId {
+    fn from(id: EventId) -> Self {
+        id.0
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Copy)]
+/// The ID of a household member
+pub struct MemberId(pub i64);
+
+impl Deref for MemberId {
+    type Target = i64;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Display for MemberId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub type DbMemberId = i64;
+impl From<DbMemberId> for MemberId {
+    fn from(id: DbMemberId) -> Self {
+        Self(id)
+    }
+}
+impl From<MemberId> for DbMemberId {
+    fn from(id: MemberId) -> Self {
+        id.0
+    }
+}
+
+/// A person in the household that completions can be attributed to
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Member {
+    pub id: MemberId,
+    pub name: String,
+}
+
+pub struct DbMember {
+    pub id: DbMemberId,
+    pub name: String,
+}
+
+impl From<DbMember> for Member {
+    fn from(member: DbMember) -> Self {
+        Self {
+            id: member.id.into(),
+            name: member.name,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Copy)]
+/// The ID of a user who can log in
+pub struct UserId(pub i64);
+
+impl Deref for UserId {
+    type Target = i64;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Display for UserId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub type DbUserId = i64;
+impl From<DbUserId> for UserId {
+    fn from(id: DbUserId) -> Self {
+        Self(id)
+    }
+}
+impl From<UserId> for DbUserId {
+    fn from(id: UserId) -> Self {
+        id.0
+    }
+}
+
+/// What a user is allowed to do, from least to most
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    /// Can only look, such as a display on the wall
+    Viewer,
+    /// Can also record, snooze and skip chores, and undo and redo
+    Member,
+    /// Can also add, edit and delete chores, members and vacations
+    Admin,
+}
+
+impl Role {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Viewer => "viewer",
+            Role::Member => "member",
+            Role::Admin => "admin",
+        }
+    }
+
+    /// Whether the role can record, snooze and skip chores
+    pub fn can_record(&self) -> bool {
+        *self >= Role::Member
+    }
+
+    /// Whether the role can reach the manager
+    pub fn can_manage(&self) -> bool {
+        *self >= Role::Admin
+    }
+}
+
+impl FromStr for Role {
+    type Err = color_eyre::eyre::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "viewer" => Ok(Role::Viewer),
+            "member" => Ok(Role::Member),
+            "admin" => Ok(Role::Admin),
+            _ => Err(color_eyre::eyre::eyre!("Unknown role '{s}'")),
+        }
+    }
+}
+
+/// Someone who can log in. Their password hash is only read when logging in
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct User {
+    pub id: UserId,
+    pub username: String,
+    pub role: Role,
+}
+
+pub struct DbUser {
+    pub id: DbUserId,
+    pub username: String,
+    pub role: String,
+}
+
+impl TryFrom<DbUser> for User {
+    type Error = color_eyre::eyre::Error;
+
+    fn try_from(user: DbUser) -> Result<Self> {
+        Ok(Self {
+            id: user.id.into(),
+            role: user
+                .role
+                .parse()
+                .wrap_err_with(|| format!("Failed to parse role of user {id}", id = user.id))?,
+            username: user.username,
+        })
+    }
+}
+
+/// What an API token is allowed to do
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum Scope {
+    /// Read chores, members, events and stats
+    #[serde(rename = "chores:read")]
+    ChoresRead,
+    /// Add, edit and delete chores and members, and edit or delete past
+    /// events
+    #[serde(rename = "chores:write")]
+    ChoresWrite,
+    /// Record, snooze, skip, undo and redo events
+    #[serde(rename = "events:write")]
+    EventsWrite,
+}
+
+impl Scope {
+    pub const ALL: [Scope; 3] = [Scope::ChoresRead, Scope::ChoresWrite, Scope::EventsWrite];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Scope::ChoresRead => "chores:read",
+            Scope::ChoresWrite => "chores:write",
+            Scope::EventsWrite => "events:write",
+        }
+    }
+}
+
+impl FromStr for Scope {
+    type Err = color_eyre::eyre::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "chores:read" => Ok(Scope::ChoresRead),
+            "chores:write" => Ok(Scope::ChoresWrite),
+            "events:write" => Ok(Scope::EventsWrite),
+            _ => Err(color_eyre::eyre::eyre!("Unknown scope '{s}'")),
+        }
+    }
+}
+
+/// A token that scripts can use the API with instead of logging in
+#[derive(Clone, Debug)]
+pub struct ApiToken {
+    pub id: i64,
+    pub name: String,
+    pub scopes: Vec<Scope>,
+    pub created_at: Timestamp,
+    pub last_used_at: Option<Timestamp>,
+    pub revoked_at: Option<Timestamp>,
+}
+
+impl ApiToken {
+    pub fn has_scope(&self, scope: Scope) -> bool {
+        self.scopes.contains(&scope)
+    }
+}
+
+pub struct DbApiToken {
+    pub id: i64,
+    pub name: String,
+    pub scopes: String,
+    pub created_at: i64,
+    pub last_used_at: Option<i64>,
+    pub revoked_at: Option<i64>,
+}
+
+impl TryFrom<DbApiToken> for ApiToken {
+    type Error = color_eyre::eyre::Error;
+
+    fn try_from(token: DbApiToken) -> Result<Self> {
+        let timestamp = |ms: i64| {
+            Timestamp::from_millisecond(ms)
+                .wrap_err_with(|| format!("Invalid timestamp for API token {id}", id = token.id))
+        };
+        Ok(Self {
+            id: token.id,
+            scopes: token
+                .scopes
+                .split_whitespace()
+                .map(str::parse)
+                .collect::<Result<_>>()
+                .wrap_err_with(|| {
+                    format!("Failed to parse scopes of API token {id}", id = token.id)
+                })?,
+            created_at: timestamp(token.created_at)?,
+            last_used_at: token.last_used_at.map(timestamp).transpose()?,
+            revoked_at: token.revoked_at.map(timestamp).transpose()?,
+            name: token.name,
         })
     }
 }
 
+/// What happened to a chore
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum EventKind {
+    /// The chore was done
+    #[default]
+    Completed,
+    /// The chore was put off until a chosen time
+    Snoozed,
+    /// The chore's current occurrence was skipped
+    Skipped,
+}
+
+impl EventKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EventKind::Completed => "completed",
+            EventKind::Snoozed => "snoozed",
+            EventKind::Skipped => "skipped",
+        }
+    }
+}
+
+impl FromStr for EventKind {
+    type Err = color_eyre::eyre::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "completed" => Ok(EventKind::Completed),
+            "snoozed" => Ok(EventKind::Snoozed),
+            "skipped" => Ok(EventKind::Skipped),
+            _ => Err(color_eyre::eyre::eyre!("Unknown event kind '{s}'")),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Event {
+    pub id: EventId,
     pub chore_id: ChoreId,
     pub timestamp: Zoned,
+    /// Who completed the chore, if anyone was picked
+    #[serde(default)]
+    pub member_id: Option<MemberId>,
+    /// Who was logged in when the event was recorded
+    #[serde(default)]
+    pub user_id: Option<UserId>,
+    #[serde(default)]
+    pub kind: EventKind,
+    /// When a snoozed or skipped chore is next due
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub until: Option<Zoned>,
 }
 
 impl AsRef<Event> for Event {
@@ -104,32 +586,229 @@ impl AsRef<Event> for Event {
 }
 
 pub struct DbEvent {
+    pub id: DbEventId,
     pub chore_id: DbChoreId,
-    pub timestamp: String,
+    pub timestamp: DbTimestamp,
+    pub time_zone: String,
+    pub member_id: Option<DbMemberId>,
+    pub user_id: Option<DbUserId>,
+    pub kind: String,
+    pub until: Option<DbTimestamp>,
 }
 
 impl From<Event> for DbEvent {
     fn from(event: Event) -> Self {
+        let (timestamp, time_zone) = to_db_timestamp(&event.timestamp);
         Self {
+            id: event.id.into(),
             chore_id: event.chore_id.into(),
-            timestamp: event.timestamp.to_string(),
+            timestamp,
+            time_zone,
+            member_id: event.member_id.map(Into::into),
+            user_id: event.user_id.map(Into::into),
+            kind: event.kind.as_str().to_string(),
+            until: event.until.map(|until| until.timestamp().as_millisecond()),
         }
     }
 }
 
+/// An undone event waiting on the redo stack
+pub struct DbRedoEvent {
+    /// The ID the event had before it was undone, if it had one
+    pub event_id: Option<DbEventId>,
+    pub chore_id: DbChoreId,
+    pub timestamp: DbTimestamp,
+    pub time_zone: String,
+    pub member_id: Option<DbMemberId>,
+    pub user_id: Option<DbUserId>,
+    pub kind: String,
+    pub until: Option<DbTimestamp>,
+    pub idempotency_key: Option<String>,
+}
+
 impl TryFrom<DbEvent> for Event {
     type Error = color_eyre::eyre::Error;
 
     fn try_from(event: DbEvent) -> Result<Self> {
         Ok(Self {
+            id: event.id.into(),
             chore_id: event.chore_id.into(),
-            timestamp: event.timestamp.parse().wrap_err_with(|| {
-                format!(
-                    "Failed to parse timestamp '{timestamp}' for event with chore ID {id}",
-                    id = event.chore_id,
-                    timestamp = event.timestamp
-                )
-            })?,
+            timestamp: from_db_timestamp(event.timestamp, &event.time_zone).wrap_err_with(
+                || format!("Failed to load timestamp for event {id}", id = event.id),
+            )?,
+            member_id: event.member_id.map(Into::into),
+            user_id: event.user_id.map(Into::into),
+            kind: event
+                .kind
+                .parse()
+                .wrap_err_with(|| format!("Failed to load kind of event {id}", id = event.id))?,
+            until: event
+                .until
+                .map(|until| from_db_timestamp(until, &event.time_zone))
+                .transpose()
+                .wrap_err_with(|| {
+                    format!("Failed to load postponement of event {id}", id = event.id)
+                })?,
+        })
+    }
+}
+
+/// Milliseconds since the Unix epoch
+pub type DbTimestamp = i64;
+
+/// Split a zoned datetime into the instant it refers to and the name of its
+/// time zone, which is an IANA name unless the zone is a fixed offset
+pub fn to_db_timestamp(timestamp: &Zoned) -> (DbTimestamp, String) {
+    let millis = timestamp.timestamp().as_millisecond();
+    let time_zone = match timestamp.time_zone().iana_name() {
+        Some(name) => name.to_string(),
+        None => {
+            // fixed offsets and the like only have a name in the bracketed
+            // annotation of the RFC 9557 format
+            let formatted = timestamp.to_string();
+            formatted
+                .split_once('[')
+                .and_then(|(_, annotation)| annotation.strip_suffix(']'))
+                .unwrap_or("UTC")
+                .to_string()
+        }
+    };
+    (millis, time_zone)
+}
+
+/// Join an instant and a time zone name from the database back into a zoned
+/// datetime
+pub fn from_db_timestamp(millis: DbTimestamp, time_zone: &str) -> Result<Zoned> {
+    let timestamp = Timestamp::from_millisecond(millis)
+        .wrap_err_with(|| format!("Timestamp {millis} is out of range"))?;
+    format!("{timestamp}[{time_zone}]")
+        .parse()
+        .wrap_err_with(|| format!("Failed to load time zone '{time_zone}'"))
+}
+
+/// The order to list events in
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum EventOrder {
+    #[default]
+    Newest,
+    Oldest,
+}
+
+/// Marks a position in a list of events so that the following page can be
+/// fetched, formatted as `<timestamp>_<id>` so that it is safe to use in URLs
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventCursor {
+    pub(super) timestamp: DbTimestamp,
+    pub(super) id: DbEventId,
+}
+
+impl Display for EventCursor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{timestamp}_{id}",
+            timestamp = self.timestamp,
+            id = self.id
+        )
+    }
+}
+
+impl std::str::FromStr for EventCursor {
+    type Err = color_eyre::eyre::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        use color_eyre::eyre::OptionExt;
+
+        let (timestamp, id) = s
+            .split_once('_')
+            .ok_or_eyre("Cursor is missing its separator")?;
+
+        Ok(Self {
+            timestamp: timestamp
+                .parse()
+                .wrap_err("Cursor has an invalid timestamp")?,
+            id: id.parse().wrap_err("Cursor has an invalid event ID")?,
+        })
+    }
+}
+
+impl Serialize for EventCursor {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Which events to list and how
+#[derive(Clone, Debug, Default)]
+pub struct EventFilter {
+    /// Only list events for this chore, or for all chores if `None`
+    pub chore_id: Option<ChoreId>,
+    /// Only list events that happened at or after this time
+    pub since: Option<Timestamp>,
+    /// Only list events that happened before this time
+    pub until: Option<Timestamp>,
+    pub order: EventOrder,
+    /// The maximum number of events to return
+    pub limit: u32,
+    /// Continue listing after this cursor from a previous page
+    pub cursor: Option<EventCursor>,
+}
+
+/// A page of events, along with the cursor to fetch the next page if there
+/// are more events
+#[derive(Debug, Clone, Serialize)]
+pub struct EventPage {
+    pub events: Vec<Event>,
+    pub next_cursor: Option<EventCursor>,
+}
+
+/// A period during which the whole household is away
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Vacation {
+    pub id: i64,
+    pub start: Zoned,
+    /// `None` until the vacation is ended
+    pub end: Option<Zoned>,
+}
+
+impl Vacation {
+    pub fn is_active(&self, now: &Zoned) -> bool {
+        self.start <= *now && self.end.as_ref().is_none_or(|end| end > now)
+    }
+
+    /// The vacation as a pause, with open-ended vacations lasting until `now`
+    pub fn as_pause(&self, now: Timestamp) -> Pause {
+        Pause {
+            start: self.start.timestamp(),
+            end: self.end.as_ref().map_or(now, |end| end.timestamp()),
+        }
+    }
+}
+
+pub struct DbVacation {
+    pub id: i64,
+    pub starts_at: DbTimestamp,
+    pub ends_at: Option<DbTimestamp>,
+    pub time_zone: String,
+}
+
+impl TryFrom<DbVacation> for Vacation {
+    type Error = color_eyre::eyre::Error;
+
+    fn try_from(vacation: DbVacation) -> Result<Self> {
+        Ok(Self {
+            id: vacation.id,
+            start: from_db_timestamp(vacation.starts_at, &vacation.time_zone).wrap_err_with(
+                || format!("Failed to load start of vacation {id}", id = vacation.id),
+            )?,
+            end: vacation
+                .ends_at
+                .map(|ends_at| from_db_timestamp(ends_at, &vacation.time_zone))
+                .transpose()
+                .wrap_err_with(|| {
+                    format!("Failed to load end of vacation {id}", id = vacation.id)
+                })?,
         })
     }
 }
@@ -139,7 +818,72 @@ pub struct ChoreEvent {
     pub id: ChoreId,
     pub name: String,
     pub interval: Span,
+    #[serde(default)]
+    pub schedule: Schedule,
+    #[serde(default)]
+    pub room: Option<String>,
+    #[serde(default)]
+    pub icon: Option<String>,
+    #[serde(default)]
+    pub colour: Option<String>,
     pub timestamp: Option<Zoned>,
+    /// Set if the chore was snoozed or skipped since it was last completed
+    #[serde(default)]
+    pub postponed_until: Option<Zoned>,
+    /// Household vacations, which due dates don't accrue during
+    #[serde(skip)]
+    pub pauses: Vec<Pause>,
+}
+
+impl ChoreEvent {
+    /// When the chore is next due, or `None` if it has never been completed
+    /// or postponed
+    pub fn next_due(&self) -> Option<Zoned> {
+        if let Some(postponed_until) = &self.postponed_until {
+            return Some(postponed_until.clone());
+        }
+        self.timestamp.as_ref().map(|timestamp| {
+            self.schedule.next_due_with_pauses(
+                self.interval,
+                timestamp,
+                &self.pauses,
+                Timestamp::now(),
+            )
+        })
+    }
+
+    /// The times the chore is expected to fall due up to the end of `until`,
+    /// at most `limit` of them. An overdue chore is due `now`, and the times
+    /// after that assume it gets done as soon as it is due
+    pub fn projected_due(&self, now: &Zoned, until: Date, limit: usize) -> Vec<Zoned> {
+        let Some(mut due) = self.next_due() else {
+            return vec![];
+        };
+        if due < *now {
+            due = now.clone();
+        }
+
+        let mut projected = vec![];
+        while due.date() <= until && projected.len() < limit {
+            let next = self.schedule.next_due(self.interval, &due);
+            let stuck = next <= due;
+            projected.push(std::mem::replace(&mut due, next));
+            if stuck {
+                break;
+            }
+        }
+        projected
+    }
+
+    /// When the chore would next be due if its current occurrence was
+    /// skipped at `now`
+    pub fn next_due_when_skipped(&self, now: &Zoned) -> Zoned {
+        let current = self
+            .next_due()
+            .filter(|due| due > now)
+            .unwrap_or_else(|| now.clone());
+        self.schedule.next_due(self.interval, &current)
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -147,7 +891,14 @@ pub struct DbChoreEvent {
     pub id: DbChoreId,
     pub name: String,
     pub interval: String,
-    pub timestamp: Option<String>,
+    pub schedule: String,
+    pub room: Option<String>,
+    pub icon: Option<String>,
+    pub colour: Option<String>,
+    pub timestamp: Option<DbTimestamp>,
+    pub time_zone: Option<String>,
+    pub postponed_until: Option<DbTimestamp>,
+    pub postponed_time_zone: Option<String>,
 }
 
 impl TryFrom<DbChoreEvent> for ChoreEvent {
@@ -164,18 +915,120 @@ impl TryFrom<DbChoreEvent> for ChoreEvent {
                     interval = chore_event.interval
                 )
             })?,
+            schedule: chore_event.schedule.parse().wrap_err_with(|| {
+                format!(
+                    "Failed to parse schedule '{schedule}' for chore {id}",
+                    id = chore_event.id,
+                    schedule = chore_event.schedule
+                )
+            })?,
+            room: chore_event.room,
+            icon: chore_event.icon,
+            colour: chore_event.colour,
             timestamp: chore_event
                 .timestamp
-                .map(|timestamp| {
-                    timestamp.parse().wrap_err_with(|| {
+                .zip(chore_event.time_zone)
+                .map(|(timestamp, time_zone)| {
+                    from_db_timestamp(timestamp, &time_zone).wrap_err_with(|| {
+                        format!(
+                            "Failed to load last completion time for chore {id}",
+                            id = chore_event.id
+                        )
+                    })
+                })
+                .transpose()?,
+            postponed_until: chore_event
+                .postponed_until
+                .zip(chore_event.postponed_time_zone)
+                .map(|(until, time_zone)| {
+                    from_db_timestamp(until, &time_zone).wrap_err_with(|| {
                         format!(
-                            "Failed to parse timestamp '{timestamp}' for chore {id}",
-                            id = chore_event.id,
-                            timestamp = timestamp
+                            "Failed to load postponement for chore {id}",
+                            id = chore_event.id
                         )
                     })
                 })
                 .transpose()?,
+            pauses: Vec::new(),
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn event_cursors_round_trip() {
+        let cursor = EventCursor {
+            timestamp: 1742178276000,
+            id: 42,
+        };
+        let encoded = cursor.to_string();
+        assert_eq!(encoded.parse::<EventCursor>().unwrap(), cursor);
+    }
+
+    #[test]
+    fn invalid_event_cursors_are_rejected() {
+        assert!("xyz".parse::<EventCursor>().is_err());
+        assert!("3432".parse::<EventCursor>().is_err());
+        assert!("12_ab".parse::<EventCursor>().is_err());
+    }
+
+    #[test]
+    fn db_timestamps_round_trip() {
+        for zoned in [
+            "2025-03-16T20:24:36.123-06:00[America/Edmonton]",
+            "2025-11-02T01:30:00-07:00[America/Edmonton]",
+            "2025-03-16T20:24:36+05:30[+05:30]",
+        ] {
+            let zoned: Zoned = zoned.parse().unwrap();
+            let (millis, time_zone) = to_db_timestamp(&zoned);
+            assert_eq!(from_db_timestamp(millis, &time_zone).unwrap(), zoned);
+        }
+    }
+
+    #[test]
+    fn db_timestamps_order_across_offsets() {
+        // 01:30 MDT happens an hour before 01:15 MST on the day DST ends
+        let earlier: Zoned = "2025-11-02T01:30:00-06:00[America/Edmonton]"
+            .parse()
+            .unwrap();
+        let later: Zoned = "2025-11-02T01:15:00-07:00[America/Edmonton]"
+            .parse()
+            .unwrap();
+        assert!(earlier.to_string() > later.to_string());
+        assert!(to_db_timestamp(&earlier).0 < to_db_timestamp(&later).0);
+    }
+
+    #[test]
+    fn projects_due_times_from_the_last_completion() {
+        let at = |day: i8| {
+            jiff::civil::date(2026, 10, day)
+                .at(12, 0, 0, 0)
+                .to_zoned(jiff::tz::TimeZone::UTC)
+                .unwrap()
+        };
+        let chore_event = ChoreEvent {
+            id: ChoreId(1),
+            name: "Water plants".to_string(),
+            interval: Span::new().days(3),
+            schedule: Schedule::Rolling,
+            room: None,
+            icon: None,
+            colour: None,
+            timestamp: Some(at(10)),
+            postponed_until: None,
+            pauses: vec![],
+        };
+        let until = jiff::civil::date(2026, 10, 20);
+
+        let due = chore_event.projected_due(&at(11), until, 10);
+        assert_eq!(due, [at(13), at(16), at(19)]);
+        assert_eq!(chore_event.projected_due(&at(11), until, 2).len(), 2);
+
+        // once overdue it is due now, and the next ones count from there
+        let due = chore_event.projected_due(&at(14), until, 10);
+        assert_eq!(due, [at(14), at(17), at(20)]);
+    }
+}
@@ -3,6 +3,7 @@ use color_eyre::{Result, eyre::Context};
 mod cli;
 mod db;
 mod logging;
+mod schedule;
 mod stats;
 mod web;
 
@@ -16,9 +17,32 @@ async fn main() -> Result<()> {
         .await
         .wrap_err_with(|| "Failed to connect to database")?;
 
-    web::run(cli, db)
-        .await
-        .wrap_err_with(|| "Failed to run web server")?;
+    match &cli.command {
+        Some(cli::Command::CreateAdmin { username }) => {
+            web::commands::create_user(&db, username, db::Role::Admin)
+                .await
+                .wrap_err_with(|| "Failed to create admin")?
+        }
+        Some(cli::Command::CreateUser { username, role }) => {
+            web::commands::create_user(&db, username, *role)
+                .await
+                .wrap_err_with(|| "Failed to create user")?
+        }
+        Some(cli::Command::CreateToken { name, scopes }) => {
+            web::commands::create_api_token(&db, name, scopes)
+                .await
+                .wrap_err_with(|| "Failed to create API token")?
+        }
+        Some(cli::Command::ListTokens) => web::commands::list_api_tokens(&db)
+            .await
+            .wrap_err_with(|| "Failed to list API tokens")?,
+        Some(cli::Command::RevokeToken { id }) => web::commands::revoke_api_token(&db, *id)
+            .await
+            .wrap_err_with(|| "Failed to revoke API token")?,
+        None => web::run(cli, db)
+            .await
+            .wrap_err_with(|| "Failed to run web server")?,
+    }
 
     Ok(())
 }
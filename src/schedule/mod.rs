@@ -0,0 +1,360 @@
+use std::fmt::Display;
+
+use color_eyre::{
+    Result,
+    eyre::{Context, eyre},
+};
+use jiff::{
+    Span, Timestamp, Unit, Zoned,
+    civil::{Date, Weekday},
+};
+use serde::{Deserialize, Serialize};
+
+mod pause;
+mod rrule;
+pub use pause::Pause;
+pub use rrule::{Frequency, RRule};
+
+/// How a chore's due date is worked out from when it was last completed
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub enum Schedule {
+    /// Due one interval after the chore was last completed
+    #[default]
+    Rolling,
+    /// Due on these days of every week, e.g. `weekly: tu, th`
+    Weekdays(Vec<Weekday>),
+    /// Due on these days of every month, counting back from the end of the
+    /// month if negative, e.g. `monthly: 1, -1`
+    DaysOfMonth(Vec<i8>),
+    /// Due on the occurrences of a recurrence rule, e.g.
+    /// `RRULE:FREQ=MONTHLY;BYDAY=-1FR`
+    RRule(RRule),
+}
+
+impl Schedule {
+    fn as_rrule(&self) -> Option<RRule> {
+        match self {
+            Schedule::Rolling => None,
+            Schedule::Weekdays(weekdays) => Some(RRule {
+                frequency: Frequency::Weekly,
+                interval: 1,
+                by_day: weekdays.iter().map(|weekday| (None, *weekday)).collect(),
+                by_month_day: Vec::new(),
+                by_month: Vec::new(),
+                start: None,
+            }),
+            Schedule::DaysOfMonth(days) => Some(RRule {
+                frequency: Frequency::Monthly,
+                interval: 1,
+                by_day: Vec::new(),
+                by_month_day: days.clone(),
+                by_month: Vec::new(),
+                start: None,
+            }),
+            Schedule::RRule(rrule) => Some(rrule.clone()),
+        }
+    }
+
+    /// The first calendar date strictly after `date` that the chore falls due
+    /// on, or `None` for rolling schedules
+    pub fn next_occurrence_after(&self, date: Date) -> Option<Date> {
+        self.as_rrule().and_then(|rrule| rrule.next_after(date))
+    }
+
+    /// Whether the chore ever falls due. Calendar schedules can ask for days
+    /// that never come, such as February 30th
+    pub fn has_occurrences(&self) -> bool {
+        self.as_rrule().is_none_or(|rrule| rrule.first().is_some())
+    }
+
+    /// When a chore that was last completed at `last_completed` is next due.
+    /// Calendar schedules fall due at the start of the day in the time zone
+    /// the chore was completed in
+    pub fn next_due(&self, interval: Span, last_completed: &Zoned) -> Zoned {
+        self.next_occurrence_after(last_completed.date())
+            .and_then(|date| date.to_zoned(last_completed.time_zone().clone()).ok())
+            .unwrap_or_else(|| last_completed.saturating_add(interval))
+    }
+
+    /// Like [`Schedule::next_due`], but due dates don't accrue while the
+    /// household is paused. Calendar schedules move on to the next
+    /// occurrence once the pause is over
+    pub fn next_due_with_pauses(
+        &self,
+        interval: Span,
+        last_completed: &Zoned,
+        pauses: &[Pause],
+        now: Timestamp,
+    ) -> Zoned {
+        let due = self.next_due(interval, last_completed);
+        let delayed = pause::delay_for_pauses(pauses, last_completed, due.clone(), now);
+        if delayed == due || *self == Schedule::Rolling {
+            return delayed;
+        }
+
+        delayed
+            .date()
+            .yesterday()
+            .ok()
+            .and_then(|date| self.next_occurrence_after(date))
+            .and_then(|date| date.to_zoned(last_completed.time_zone().clone()).ok())
+            .unwrap_or(delayed)
+    }
+
+    /// Whether the chore comes up at least once a day
+    pub fn is_daily(&self, interval: &Span) -> bool {
+        let today = Zoned::now().date();
+        match self {
+            Schedule::Rolling => {
+                interval
+                    .total((Unit::Day, today))
+                    .expect("can calculate total days")
+                    < 1.0
+            }
+            _ => {
+                let first = today
+                    .yesterday()
+                    .ok()
+                    .and_then(|yesterday| self.next_occurrence_after(yesterday));
+                let second = first.and_then(|first| self.next_occurrence_after(first));
+                match (first, second) {
+                    (Some(first), Some(second)) => {
+                        first.until(second).is_ok_and(|span| span.get_days() <= 1)
+                    }
+                    _ => false,
+                }
+            }
+        }
+    }
+}
+
+impl Display for Schedule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Schedule::Rolling => Ok(()),
+            Schedule::Weekdays(weekdays) => {
+                let weekdays: Vec<&str> = weekdays
+                    .iter()
+                    .map(|weekday| rrule::weekday_code(*weekday))
+                    .collect();
+                write!(f, "weekly: {}", weekdays.join(", "))
+            }
+            Schedule::DaysOfMonth(days) => {
+                let days: Vec<String> = days.iter().map(|day| day.to_string()).collect();
+                write!(f, "monthly: {}", days.join(", "))
+            }
+            Schedule::RRule(rrule) => write!(f, "{rrule}"),
+        }
+    }
+}
+
+impl std::str::FromStr for Schedule {
+    type Err = color_eyre::eyre::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        if s.is_empty() || s.eq_ignore_ascii_case("rolling") {
+            return Ok(Schedule::Rolling);
+        }
+
+        let lower = s.to_lowercase();
+        let items = |list: &str| -> Vec<String> {
+            list.split([',', ' '])
+                .filter(|item| !item.is_empty())
+                .map(|item| item.to_string())
+                .collect()
+        };
+        if let Some(weekdays) = lower.strip_prefix("weekly:") {
+            let weekdays = items(weekdays)
+                .iter()
+                .map(|weekday| rrule::parse_weekday(weekday))
+                .collect::<Result<Vec<Weekday>>>()?;
+            if weekdays.is_empty() {
+                return Err(eyre!("Weekly schedules need at least one day"));
+            }
+            Ok(Schedule::Weekdays(weekdays))
+        } else if let Some(days) = lower.strip_prefix("monthly:") {
+            let days = items(days)
+                .iter()
+                .map(|day| {
+                    day.parse::<i8>()
+                        .ok()
+                        .filter(|day| *day != 0 && (-31..=31).contains(day))
+                        .ok_or_else(|| eyre!("'{day}' is not a day of the month"))
+                })
+                .collect::<Result<Vec<i8>>>()?;
+            if days.is_empty() {
+                return Err(eyre!("Monthly schedules need at least one day"));
+            }
+            Ok(Schedule::DaysOfMonth(days))
+        } else {
+            Ok(Schedule::RRule(
+                s.parse()
+                    .wrap_err_with(|| format!("Invalid schedule '{s}'"))?,
+            ))
+        }
+    }
+}
+
+impl From<Schedule> for String {
+    fn from(schedule: Schedule) -> Self {
+        schedule.to_string()
+    }
+}
+
+impl TryFrom<String> for Schedule {
+    type Error = color_eyre::eyre::Error;
+
+    fn try_from(s: String) -> Result<Self> {
+        s.parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use jiff::civil::date;
+
+    use super::*;
+
+    fn occurrences(schedule: &str, after: Date, count: usize) -> Vec<Date> {
+        let schedule: Schedule = schedule.parse().expect("can parse schedule");
+        let mut dates = Vec::with_capacity(count);
+        let mut date = after;
+        for _ in 0..count {
+            date = schedule
+                .next_occurrence_after(date)
+                .expect("schedule has another occurrence");
+            dates.push(date);
+        }
+        dates
+    }
+
+    #[test]
+    fn can_parse_and_print_schedules() {
+        for (input, printed) in [
+            ("", ""),
+            ("weekly: tue, Thursday", "weekly: TU, TH"),
+            ("monthly: 1,-1", "monthly: 1, -1"),
+            (
+                "rrule:freq=monthly;byday=-1fr",
+                "RRULE:FREQ=MONTHLY;BYDAY=-1FR",
+            ),
+            (
+                "DTSTART:20250107 RRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=TU",
+                "DTSTART:20250107 RRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=TU",
+            ),
+        ] {
+            let schedule: Schedule = input.parse().expect("can parse schedule");
+            assert_eq!(schedule.to_string(), printed);
+            assert_eq!(printed.parse::<Schedule>().unwrap(), schedule);
+        }
+    }
+
+    #[test]
+    fn invalid_schedules_are_rejected() {
+        for input in [
+            "weekly:",
+            "weekly: funday",
+            "monthly: 0",
+            "monthly: 32",
+            "FREQ=HOURLY",
+            "RRULE:BYDAY=MO",
+            "RRULE:FREQ=WEEKLY;COUNT=3",
+            "FREQ=WEEKLY;BYDAY=ÉA",
+            "FREQ=WEEKLY;BYDAY=1É",
+        ] {
+            assert!(input.parse::<Schedule>().is_err(), "{input} should fail");
+        }
+    }
+
+    #[test]
+    fn impossible_schedules_have_no_occurrences() {
+        for input in [
+            "FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=30",
+            "FREQ=MONTHLY;BYMONTH=4;BYMONTHDAY=31",
+            "FREQ=MONTHLY;BYDAY=5MO;BYMONTHDAY=1",
+        ] {
+            let schedule: Schedule = input.parse().expect("can parse schedule");
+            assert!(!schedule.has_occurrences(), "{input} should never come up");
+        }
+        for input in ["", "monthly: 31", "FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=29"] {
+            let schedule: Schedule = input.parse().expect("can parse schedule");
+            assert!(schedule.has_occurrences(), "{input} should come up");
+        }
+    }
+
+    #[test]
+    fn weekly_schedules_land_on_their_weekdays() {
+        // 2025-01-01 was a Wednesday
+        assert_eq!(
+            occurrences("weekly: tu, th", date(2025, 1, 1), 3),
+            vec![date(2025, 1, 2), date(2025, 1, 7), date(2025, 1, 9)]
+        );
+    }
+
+    #[test]
+    fn monthly_schedules_skip_missing_days() {
+        assert_eq!(
+            occurrences("monthly: 31", date(2025, 1, 31), 2),
+            vec![date(2025, 3, 31), date(2025, 5, 31)]
+        );
+        assert_eq!(
+            occurrences("monthly: -1", date(2025, 1, 31), 2),
+            vec![date(2025, 2, 28), date(2025, 3, 31)]
+        );
+    }
+
+    #[test]
+    fn rrules_respect_intervals_and_ordinals() {
+        assert_eq!(
+            occurrences(
+                "DTSTART:20250107 RRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=TU",
+                date(2025, 1, 1),
+                3
+            ),
+            vec![date(2025, 1, 7), date(2025, 1, 21), date(2025, 2, 4)]
+        );
+        assert_eq!(
+            occurrences("RRULE:FREQ=MONTHLY;BYDAY=-1FR", date(2025, 1, 1), 2),
+            vec![date(2025, 1, 31), date(2025, 2, 28)]
+        );
+        assert_eq!(
+            occurrences(
+                "DTSTART:20250301 RRULE:FREQ=YEARLY;BYMONTH=3,9;BYMONTHDAY=1",
+                date(2025, 1, 1),
+                3
+            ),
+            vec![date(2025, 3, 1), date(2025, 9, 1), date(2026, 3, 1)]
+        );
+    }
+
+    #[test]
+    fn calendar_schedules_do_not_drift() {
+        let schedule: Schedule = "weekly: tu".parse().unwrap();
+        // done a day late on Wednesday, the next one is still due on Tuesday
+        let late: Zoned = "2025-01-08T18:00:00-07:00[America/Edmonton]"
+            .parse()
+            .unwrap();
+        let next = schedule.next_due(Span::new().weeks(1), &late);
+        assert_eq!(next.date(), date(2025, 1, 14));
+        assert_eq!(next.time_zone(), late.time_zone());
+
+        let rolling = Schedule::Rolling.next_due(Span::new().weeks(1), &late);
+        assert_eq!(rolling.date(), date(2025, 1, 15));
+    }
+
+    #[test]
+    fn daily_schedules_are_detected() {
+        let week = Span::new().weeks(1);
+        assert!(Schedule::Rolling.is_daily(&Span::new().hours(12)));
+        assert!(!Schedule::Rolling.is_daily(&week));
+        assert!(
+            "RRULE:FREQ=DAILY"
+                .parse::<Schedule>()
+                .unwrap()
+                .is_daily(&week)
+        );
+        assert!(!"weekly: mo".parse::<Schedule>().unwrap().is_daily(&week));
+    }
+}
@@ -0,0 +1,97 @@
+use jiff::{SignedDuration, Timestamp, Zoned};
+
+/// A stretch of time during which chores don't fall due, such as a household
+/// vacation. Open-ended pauses are cut off at the current time
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Pause {
+    pub start: Timestamp,
+    pub end: Timestamp,
+}
+
+/// How much of `from..to` is covered by pauses, counting overlapping pauses once
+pub fn paused_between(pauses: &[Pause], from: Timestamp, to: Timestamp) -> SignedDuration {
+    let mut clipped: Vec<(Timestamp, Timestamp)> = pauses
+        .iter()
+        .map(|pause| (pause.start.max(from), pause.end.min(to)))
+        .filter(|(start, end)| start < end)
+        .collect();
+    clipped.sort();
+
+    let mut total = SignedDuration::ZERO;
+    let mut covered_until = from;
+    for (start, end) in clipped {
+        let start = start.max(covered_until);
+        if start < end {
+            total += start.duration_until(end);
+            covered_until = end;
+        }
+    }
+    total
+}
+
+/// Push `due` back by the time paused since `last_completed`, as if the clock
+/// stopped during pauses. If `due` has passed by `now`, time paused while the
+/// chore was overdue doesn't count either
+pub fn delay_for_pauses(
+    pauses: &[Pause],
+    last_completed: &Zoned,
+    due: Zoned,
+    now: Timestamp,
+) -> Zoned {
+    let mut due = due;
+    let mut delayed = SignedDuration::ZERO;
+    loop {
+        let paused = paused_between(pauses, last_completed.timestamp(), due.timestamp());
+        if paused <= delayed {
+            break;
+        }
+        due = due.saturating_add(paused - delayed);
+        delayed = paused;
+    }
+
+    if due.timestamp() < now {
+        let paused_while_overdue = paused_between(pauses, due.timestamp(), now);
+        due = due.saturating_add(paused_while_overdue);
+    }
+    due
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jiff::{Span, civil::date, tz::TimeZone};
+
+    fn day(day: i8) -> Zoned {
+        date(2025, 3, day)
+            .to_zoned(TimeZone::UTC)
+            .expect("can create zoned date")
+    }
+
+    fn pause(start: i8, end: i8) -> Pause {
+        Pause {
+            start: day(start).timestamp(),
+            end: day(end).timestamp(),
+        }
+    }
+
+    #[test]
+    fn overlapping_pauses_count_once() {
+        let pauses = [pause(3, 10), pause(5, 12), pause(20, 22)];
+        let paused = paused_between(&pauses, day(1).timestamp(), day(21).timestamp());
+        assert_eq!(paused, SignedDuration::from_hours(24 * 10));
+    }
+
+    #[test]
+    fn due_dates_stop_accruing_during_pauses() {
+        // done on the 1st, due a week later, but away from the 4th to the 18th
+        let due = day(1).saturating_add(Span::new().days(7));
+        let delayed = delay_for_pauses(&[pause(4, 18)], &day(1), due, day(20).timestamp());
+        assert_eq!(delayed, day(22));
+
+        // away from the 10th to the 15th after the chore fell due on the 8th,
+        // so only the days before leaving and after coming back are overdue
+        let due = day(1).saturating_add(Span::new().days(7));
+        let delayed = delay_for_pauses(&[pause(10, 15)], &day(1), due, day(16).timestamp());
+        assert_eq!(delayed, day(13));
+    }
+}
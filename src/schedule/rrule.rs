@@ -0,0 +1,328 @@
+use std::fmt::Display;
+
+use color_eyre::{
+    Result,
+    eyre::{Context, OptionExt, eyre},
+};
+use jiff::civil::{Date, Weekday, date};
+
+/// How often an [`RRule`] repeats
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+/// The subset of RFC 5545 recurrence rules that make sense for chores:
+/// `FREQ`, `INTERVAL`, `BYDAY`, `BYMONTHDAY` and `BYMONTH`, optionally
+/// preceded by a `DTSTART` date that intervals are counted from
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RRule {
+    pub frequency: Frequency,
+    /// Only every `interval`th day / week / month / year is used
+    pub interval: i32,
+    /// Days of the week, optionally limited to the nth one in the month (or
+    /// in the year for yearly rules without `BYMONTH`), counting back from
+    /// the end if negative
+    pub by_day: Vec<(Option<i8>, Weekday)>,
+    /// Days of the month, counting back from the end of the month if negative
+    pub by_month_day: Vec<i8>,
+    pub by_month: Vec<i8>,
+    /// The date intervals are counted from, defaulting to 1970-01-01
+    pub start: Option<Date>,
+}
+
+/// How far ahead to look for the next occurrence before giving up
+const MAX_SEARCH_DAYS: usize = 366 * 10;
+
+impl RRule {
+    fn anchor(&self) -> Date {
+        self.start.unwrap_or(date(1970, 1, 1))
+    }
+
+    /// The first date the rule falls on, if it ever does
+    pub fn first(&self) -> Option<Date> {
+        self.next_after(self.anchor().yesterday().ok()?)
+    }
+
+    /// The first date strictly after `after` that the rule falls on
+    pub fn next_after(&self, after: Date) -> Option<Date> {
+        let mut day = after.max(self.anchor().yesterday().ok()?);
+        for _ in 0..MAX_SEARCH_DAYS {
+            day = day.tomorrow().ok()?;
+            if self.matches(day) {
+                return Some(day);
+            }
+        }
+        None
+    }
+
+    /// Whether the rule falls on the given day
+    pub fn matches(&self, day: Date) -> bool {
+        let anchor = self.anchor();
+        if day < anchor {
+            return false;
+        }
+        if !self.by_month.is_empty() && !self.by_month.contains(&day.month()) {
+            return false;
+        }
+
+        match self.frequency {
+            Frequency::Daily => {
+                let days = anchor.until(day).map_or(0, |span| span.get_days());
+                days % self.interval == 0
+                    && self.matches_month_day(day)
+                    && self.matches_weekday(day)
+            }
+            Frequency::Weekly => {
+                let weeks = week_start(anchor)
+                    .until(week_start(day))
+                    .map_or(0, |span| span.get_days())
+                    / 7;
+                let weekday_matches = if self.by_day.is_empty() {
+                    day.weekday() == anchor.weekday()
+                } else {
+                    self.matches_weekday(day)
+                };
+                weeks % self.interval == 0 && weekday_matches && self.matches_month_day(day)
+            }
+            Frequency::Monthly => {
+                let months = (i32::from(day.year()) * 12 + i32::from(day.month()))
+                    - (i32::from(anchor.year()) * 12 + i32::from(anchor.month()));
+                months % self.interval == 0 && self.matches_day_within(day, anchor, false)
+            }
+            Frequency::Yearly => {
+                let years = i32::from(day.year()) - i32::from(anchor.year());
+                let month_matches = !self.by_month.is_empty() || day.month() == anchor.month();
+                years % self.interval == 0
+                    && month_matches
+                    && self.matches_day_within(day, anchor, self.by_month.is_empty())
+            }
+        }
+    }
+
+    fn matches_month_day(&self, day: Date) -> bool {
+        self.by_month_day.is_empty()
+            || self.by_month_day.iter().any(|&month_day| {
+                if month_day > 0 {
+                    day.day() == month_day
+                } else {
+                    day.days_in_month() + month_day + 1 == day.day()
+                }
+            })
+    }
+
+    fn matches_weekday(&self, day: Date) -> bool {
+        self.by_day.is_empty()
+            || self
+                .by_day
+                .iter()
+                .any(|(_, weekday)| *weekday == day.weekday())
+    }
+
+    /// Match the day within its month (or year), defaulting to the day of the
+    /// month that the rule started on
+    fn matches_day_within(&self, day: Date, anchor: Date, ordinals_in_year: bool) -> bool {
+        if self.by_month_day.is_empty() && self.by_day.is_empty() {
+            return day.day() == anchor.day();
+        }
+
+        let (position, length) = if ordinals_in_year {
+            (day.day_of_year(), day.days_in_year())
+        } else {
+            (i16::from(day.day()), i16::from(day.days_in_month()))
+        };
+        let nth_from_start = (position - 1) / 7 + 1;
+        let nth_from_end = (length - position) / 7 + 1;
+
+        let weekday_matches = self.by_day.is_empty()
+            || self.by_day.iter().any(|&(nth, weekday)| {
+                weekday == day.weekday()
+                    && match nth {
+                        None => true,
+                        Some(nth) if nth > 0 => i16::from(nth) == nth_from_start,
+                        Some(nth) => i16::from(-nth) == nth_from_end,
+                    }
+            });
+        weekday_matches && self.matches_month_day(day)
+    }
+}
+
+fn week_start(day: Date) -> Date {
+    let offset = i64::from(day.weekday().to_monday_zero_offset());
+    day.checked_sub(jiff::Span::new().days(offset))
+        .unwrap_or(day)
+}
+
+/// Parse a weekday from its RFC 5545 code (`MO`) or its English name (`mon`,
+/// `monday`)
+pub fn parse_weekday(s: &str) -> Result<Weekday> {
+    let s = s.trim().to_lowercase();
+    let weekdays = [
+        ("monday", Weekday::Monday),
+        ("tuesday", Weekday::Tuesday),
+        ("wednesday", Weekday::Wednesday),
+        ("thursday", Weekday::Thursday),
+        ("friday", Weekday::Friday),
+        ("saturday", Weekday::Saturday),
+        ("sunday", Weekday::Sunday),
+    ];
+    weekdays
+        .into_iter()
+        .find(|(name, _)| s.len() >= 2 && name.starts_with(&s))
+        .map(|(_, weekday)| weekday)
+        .ok_or_else(|| eyre!("'{s}' is not a day of the week"))
+}
+
+pub fn weekday_code(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Monday => "MO",
+        Weekday::Tuesday => "TU",
+        Weekday::Wednesday => "WE",
+        Weekday::Thursday => "TH",
+        Weekday::Friday => "FR",
+        Weekday::Saturday => "SA",
+        Weekday::Sunday => "SU",
+    }
+}
+
+fn parse_list<T, F>(value: &str, parse: F) -> Result<Vec<T>>
+where
+    F: Fn(&str) -> Result<T>,
+{
+    value.split(',').map(|item| parse(item.trim())).collect()
+}
+
+fn parse_ranged(value: &str, min: i8, max: i8, name: &str) -> Result<i8> {
+    let parsed: i8 = value
+        .parse()
+        .wrap_err_with(|| format!("'{value}' is not a valid {name}"))?;
+    if parsed == 0 || parsed < min || parsed > max {
+        return Err(eyre!("{name} {parsed} is out of range"));
+    }
+    Ok(parsed)
+}
+
+impl std::str::FromStr for RRule {
+    type Err = color_eyre::eyre::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut start = None;
+        let mut rule = None;
+        for token in s.split_whitespace() {
+            let upper = token.to_uppercase();
+            if let Some(dtstart) = upper.strip_prefix("DTSTART:") {
+                let dtstart = dtstart.get(..8).ok_or_eyre("DTSTART must be a date")?;
+                start = Some(
+                    Date::strptime("%Y%m%d", dtstart)
+                        .wrap_err_with(|| format!("Invalid DTSTART '{dtstart}'"))?,
+                );
+            } else if let Some(rrule) = upper.strip_prefix("RRULE:") {
+                rule = Some(rrule.to_string());
+            } else {
+                rule = Some(upper);
+            }
+        }
+        let rule = rule.ok_or_eyre("Missing RRULE")?;
+
+        let mut frequency = None;
+        let mut rrule = RRule {
+            frequency: Frequency::Daily,
+            interval: 1,
+            by_day: Vec::new(),
+            by_month_day: Vec::new(),
+            by_month: Vec::new(),
+            start,
+        };
+        for part in rule.split(';').filter(|part| !part.is_empty()) {
+            let (key, value) = part
+                .split_once('=')
+                .ok_or_else(|| eyre!("RRULE part '{part}' is missing a value"))?;
+            match key {
+                "FREQ" => {
+                    frequency = Some(match value {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        "YEARLY" => Frequency::Yearly,
+                        _ => return Err(eyre!("Unsupported frequency '{value}'")),
+                    })
+                }
+                "INTERVAL" => {
+                    rrule.interval = value
+                        .parse()
+                        .ok()
+                        .filter(|interval| *interval > 0)
+                        .ok_or_else(|| eyre!("Invalid interval '{value}'"))?
+                }
+                "BYDAY" => {
+                    rrule.by_day = parse_list(value, |day| {
+                        if !day.is_ascii() {
+                            return Err(eyre!("'{day}' is not a day of the week"));
+                        }
+                        let split = day.len().saturating_sub(2);
+                        let (nth, weekday) = day.split_at(split);
+                        let nth = if nth.is_empty() {
+                            None
+                        } else {
+                            Some(parse_ranged(nth.trim_start_matches('+'), -53, 53, "week")?)
+                        };
+                        Ok((nth, parse_weekday(weekday)?))
+                    })?
+                }
+                "BYMONTHDAY" => {
+                    rrule.by_month_day =
+                        parse_list(value, |day| parse_ranged(day, -31, 31, "day of the month"))?
+                }
+                "BYMONTH" => {
+                    rrule.by_month = parse_list(value, |month| parse_ranged(month, 1, 12, "month"))?
+                }
+                "WKST" if value == "MO" => {}
+                _ => return Err(eyre!("Unsupported RRULE part '{part}'")),
+            }
+        }
+        rrule.frequency = frequency.ok_or_eyre("RRULE is missing FREQ")?;
+
+        Ok(rrule)
+    }
+}
+
+impl Display for RRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(start) = self.start {
+            write!(f, "DTSTART:{} ", start.strftime("%Y%m%d"))?;
+        }
+        let frequency = match self.frequency {
+            Frequency::Daily => "DAILY",
+            Frequency::Weekly => "WEEKLY",
+            Frequency::Monthly => "MONTHLY",
+            Frequency::Yearly => "YEARLY",
+        };
+        write!(f, "RRULE:FREQ={frequency}")?;
+        if self.interval != 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+        if !self.by_month.is_empty() {
+            let months: Vec<String> = self.by_month.iter().map(|m| m.to_string()).collect();
+            write!(f, ";BYMONTH={}", months.join(","))?;
+        }
+        if !self.by_month_day.is_empty() {
+            let days: Vec<String> = self.by_month_day.iter().map(|d| d.to_string()).collect();
+            write!(f, ";BYMONTHDAY={}", days.join(","))?;
+        }
+        if !self.by_day.is_empty() {
+            let days: Vec<String> = self
+                .by_day
+                .iter()
+                .map(|(nth, weekday)| match nth {
+                    Some(nth) => format!("{nth}{}", weekday_code(*weekday)),
+                    None => weekday_code(*weekday).to_string(),
+                })
+                .collect();
+            write!(f, ";BYDAY={}", days.join(","))?;
+        }
+        Ok(())
+    }
+}
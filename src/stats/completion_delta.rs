@@ -1,12 +1,19 @@
-use crate::db::{Chore, Event};
-use jiff::Unit;
+use crate::{
+    db::{Chore, Event, EventKind},
+    schedule::Pause,
+};
+use jiff::{Unit, Zoned};
 
-pub fn calculate_completion_delta_days<I>(chore: &Chore, mut events: I) -> Vec<f64>
+/// How many days late each completion after the first was. Snoozes and skips
+/// aren't completions and are ignored, and time spent paused doesn't count
+/// towards being late
+pub fn calculate_completion_delta_days<I>(chore: &Chore, events: I, pauses: &[Pause]) -> Vec<f64>
 where
     I: Iterator,
     I::Item: AsRef<Event>,
 {
     let mut delta_days = Vec::new();
+    let mut events = events.filter(|event| event.as_ref().kind == EventKind::Completed);
 
     let first_event = events.next();
     if first_event.is_none() {
@@ -16,25 +23,8 @@ where
 
     for event in events {
         let event = event.as_ref();
-
-        let expected_event_timestamp = previous_event_timestamp.saturating_add(chore.interval);
-        let actual_event_timestamp = &event.timestamp;
-        let delta = actual_event_timestamp.since(&expected_event_timestamp);
-        match delta {
-            Ok(delta) => {
-                let delta = delta.total((Unit::Day, actual_event_timestamp));
-                match delta {
-                    Ok(delta) => delta_days.push(delta),
-                    Err(e) => {
-                        tracing::warn!(
-                            "Failed to calculate delta for chore {chore:?} and event {event:?}: {e:?}"
-                        )
-                    }
-                }
-            }
-            Err(e) => tracing::warn!(
-                "Failed to calculate delta for chore {chore:?} and event {event:?}: {e:?}"
-            ),
+        if let Some(delta) = delta_days_after(chore, &previous_event_timestamp, event, pauses) {
+            delta_days.push(delta);
         }
         previous_event_timestamp = event.timestamp.clone();
     }
@@ -42,17 +32,76 @@ where
     delta_days
 }
 
+/// Pair every completion with how many days late it was, `None` for the
+/// first completion since there is nothing to be late against
+pub fn calculate_event_delta_days<'e>(
+    chore: &Chore,
+    events: &'e [Event],
+    pauses: &[Pause],
+) -> Vec<(&'e Event, Option<f64>)> {
+    let mut previous_event_timestamp: Option<&Zoned> = None;
+    events
+        .iter()
+        .filter(|event| event.kind == EventKind::Completed)
+        .map(|event| {
+            let delta = previous_event_timestamp
+                .and_then(|previous| delta_days_after(chore, previous, event, pauses));
+            previous_event_timestamp = Some(&event.timestamp);
+            (event, delta)
+        })
+        .collect()
+}
+
+fn delta_days_after(
+    chore: &Chore,
+    previous_event_timestamp: &Zoned,
+    event: &Event,
+    pauses: &[Pause],
+) -> Option<f64> {
+    let actual_event_timestamp = &event.timestamp;
+    let expected_event_timestamp = chore.next_due_after(
+        previous_event_timestamp,
+        pauses,
+        actual_event_timestamp.timestamp(),
+    );
+    let delta = actual_event_timestamp.since(&expected_event_timestamp);
+    match delta {
+        Ok(delta) => {
+            let delta = delta.total((Unit::Day, actual_event_timestamp));
+            match delta {
+                Ok(delta) => return Some(delta),
+                Err(e) => {
+                    tracing::warn!(
+                        "Failed to calculate delta for chore {chore:?} and event {event:?}: {e:?}"
+                    )
+                }
+            }
+        }
+        Err(e) => tracing::warn!(
+            "Failed to calculate delta for chore {chore:?} and event {event:?}: {e:?}"
+        ),
+    }
+    None
+}
+
 #[cfg(test)]
 mod tests {
-    use jiff::{Span, Timestamp, Zoned, tz::TimeZone};
+    use jiff::{Span, Timestamp, tz::TimeZone};
 
     use super::*;
+    use crate::schedule::Schedule;
 
     fn create_test_data() -> (Chore, Vec<Event>) {
         let chore = Chore {
             id: 1.into(),
             name: "Test Chore".to_string(),
             interval: Span::new().weeks(1),
+            schedule: Schedule::Rolling,
+            effort: 1,
+            status: Default::default(),
+            room: None,
+            icon: None,
+            colour: None,
         };
 
         let start_date = Zoned::new(
@@ -65,8 +114,13 @@ mod tests {
         let mut last_event_timestamp = start_date.clone();
 
         let mut events = vec![Event {
+            id: 1.into(),
             chore_id: chore.id,
             timestamp: start_date,
+            member_id: None,
+            user_id: None,
+            kind: EventKind::Completed,
+            until: None,
         }];
         for day_delta in day_deltas.into_iter() {
             let delta_span = Span::new().days(day_delta);
@@ -78,8 +132,13 @@ mod tests {
             let timestamp = last_event_timestamp.saturating_add(interval_span);
             last_event_timestamp = timestamp.clone();
             events.push(Event {
+                id: (events.len() as i64 + 1).into(),
                 chore_id: chore.id,
                 timestamp,
+                member_id: None,
+                user_id: None,
+                kind: EventKind::Completed,
+                until: None,
             });
         }
 
@@ -89,11 +148,93 @@ mod tests {
     #[test]
     fn can_calculate_completion_delta_days() {
         let (chore, events) = create_test_data();
-        let delta_days: Vec<i64> = calculate_completion_delta_days(&chore, events.iter())
+        let delta_days: Vec<i64> = calculate_completion_delta_days(&chore, events.iter(), &[])
             .into_iter()
             .map(|d| d as i64)
             .collect();
 
         assert_eq!(delta_days, vec![0, 2, -3, 1, 0]);
     }
+
+    #[test]
+    fn completion_delta_ignores_postponements() {
+        let (chore, mut events) = create_test_data();
+        let mut snooze = events[2].clone();
+        snooze.kind = EventKind::Snoozed;
+        snooze.timestamp = snooze.timestamp.saturating_add(Span::new().days(1));
+        snooze.until = Some(snooze.timestamp.saturating_add(Span::new().days(3)));
+        events.insert(3, snooze);
+
+        let delta_days: Vec<i64> = calculate_completion_delta_days(&chore, events.iter(), &[])
+            .into_iter()
+            .map(|d| d as i64)
+            .collect();
+
+        assert_eq!(delta_days, vec![0, 2, -3, 1, 0]);
+    }
+
+    #[test]
+    fn completion_delta_leaves_out_pauses() {
+        let (chore, events) = create_test_data();
+        // away for the three days the third completion was late
+        let pauses = [Pause {
+            start: events[1]
+                .timestamp
+                .saturating_add(Span::new().days(7))
+                .timestamp(),
+            end: events[2].timestamp.timestamp(),
+        }];
+
+        let delta_days: Vec<i64> = calculate_completion_delta_days(&chore, events.iter(), &pauses)
+            .into_iter()
+            .map(|d| d as i64)
+            .collect();
+
+        assert_eq!(delta_days, vec![0, 0, -3, 1, 0]);
+    }
+
+    #[test]
+    fn event_deltas_line_up_with_events() {
+        let (chore, events) = create_test_data();
+        let deltas: Vec<Option<i64>> = calculate_event_delta_days(&chore, &events, &[])
+            .into_iter()
+            .map(|(_, delta)| delta.map(|d| d as i64))
+            .collect();
+
+        assert_eq!(
+            deltas,
+            vec![None, Some(0), Some(2), Some(-3), Some(1), Some(0)]
+        );
+    }
+
+    #[test]
+    fn completion_delta_follows_calendar_schedules() {
+        let (mut chore, _) = create_test_data();
+        chore.schedule = "weekly: tu".parse().expect("can parse schedule");
+
+        // done on Tuesday, then a day late on Wednesday, then on time again
+        let events: Vec<Event> = ["2025-01-07T12:00", "2025-01-15T12:00", "2025-01-21T12:00"]
+            .into_iter()
+            .enumerate()
+            .map(|(i, timestamp)| Event {
+                id: (i as i64).into(),
+                chore_id: chore.id,
+                timestamp: timestamp
+                    .parse::<jiff::civil::DateTime>()
+                    .expect("can parse datetime")
+                    .to_zoned(TimeZone::UTC)
+                    .expect("can convert to zoned"),
+                member_id: None,
+                user_id: None,
+                kind: EventKind::Completed,
+                until: None,
+            })
+            .collect();
+
+        let delta_days: Vec<i64> = calculate_completion_delta_days(&chore, events.iter(), &[])
+            .into_iter()
+            .map(|d| d.floor() as i64)
+            .collect();
+        assert_eq!(delta_days, vec![1, 0]);
+    }
 }
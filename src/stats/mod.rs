@@ -1,5 +1,6 @@
-use crate::db::{ChoreId, Db};
+use crate::db::{ChoreId, Db, Event, MemberId};
 use color_eyre::{Result, eyre::WrapErr};
+use jiff::Timestamp;
 use serde::{Deserialize, Serialize};
 
 pub mod completion_delta;
@@ -13,6 +14,41 @@ pub struct ChoreStats {
     pub mean_overdue_days: f64,
     pub median_overdue_days: f64,
     pub variance_overdue_days: f64,
+    /// Snoozes and skips aren't completions, so they are only counted here
+    pub num_snoozed: usize,
+    pub num_skipped: usize,
+    /// The same numbers for each member who has completed the chore
+    pub by_member: Vec<MemberChoreStats>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MemberChoreStats {
+    /// `None` for completions that weren't attributed to anyone
+    pub member_id: Option<MemberId>,
+    pub num_completed: usize,
+    pub num_overdue: usize,
+    pub num_completed_on_time_or_early: usize,
+    pub mean_overdue_days: f64,
+}
+
+/// How much of the household's work a member did over some window
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MemberStats {
+    /// `None` for completions that weren't attributed to anyone
+    pub member_id: Option<MemberId>,
+    pub num_completed: usize,
+    /// Fraction of all completions in the window done by this member
+    pub share_of_completions: f64,
+    /// Sum of the effort of every chore the member completed
+    pub effort: u64,
+    /// Fraction of all effort in the window done by this member
+    pub share_of_effort: f64,
+    /// Fraction of the member's completions that weren't overdue
+    pub on_time_rate: f64,
+}
+
+fn is_overdue(delta: &Option<f64>) -> bool {
+    delta.is_some_and(|delta| delta >= 1.0)
 }
 
 pub async fn get_stats(db: &Db, chore_id: ChoreId) -> Result<Option<ChoreStats>> {
@@ -31,7 +67,8 @@ pub async fn get_stats(db: &Db, chore_id: ChoreId) -> Result<Option<ChoreStats>>
         )
     })?;
 
-    let deltas = completion_delta::calculate_completion_delta_days(&chore, events.iter());
+    let pauses = db.get_pauses().await.wrap_err("Failed to get vacations")?;
+    let deltas = completion_delta::calculate_completion_delta_days(&chore, events.iter(), &pauses);
 
     fn filter_overdue(deltas: &[f64]) -> impl Iterator<Item = &f64> {
         deltas.iter().filter(|delta: &&f64| *delta >= &1.0)
@@ -45,6 +82,45 @@ pub async fn get_stats(db: &Db, chore_id: ChoreId) -> Result<Option<ChoreStats>>
     let median_overdue_days = utils::median(filter_overdue(&deltas));
     let variance_overdue_days = utils::variance(mean_overdue_days, filter_overdue(&deltas));
 
+    let (num_snoozed, num_skipped) =
+        db.count_chore_postponements(chore_id)
+            .await
+            .wrap_err_with(|| {
+                format!(
+                    "Failed to count postponements for chore {chore}",
+                    chore = chore_id.0
+                )
+            })?;
+
+    let event_deltas = completion_delta::calculate_event_delta_days(&chore, &events, &pauses);
+    let mut member_ids: Vec<Option<MemberId>> =
+        events.iter().map(|event| event.member_id).collect();
+    member_ids.sort();
+    member_ids.dedup();
+    let by_member = member_ids
+        .into_iter()
+        .map(|member_id| {
+            let member_deltas: Vec<Option<f64>> = event_deltas
+                .iter()
+                .filter(|(event, _)| event.member_id == member_id)
+                .map(|(_, delta)| *delta)
+                .collect();
+            let overdue: Vec<f64> = member_deltas
+                .iter()
+                .filter(|delta| is_overdue(delta))
+                .flatten()
+                .copied()
+                .collect();
+            MemberChoreStats {
+                member_id,
+                num_completed: member_deltas.len(),
+                num_overdue: overdue.len(),
+                num_completed_on_time_or_early: member_deltas.len() - overdue.len(),
+                mean_overdue_days: utils::mean(overdue.iter()),
+            }
+        })
+        .collect();
+
     Ok(Some(ChoreStats {
         num_completed,
         num_overdue,
@@ -52,5 +128,71 @@ pub async fn get_stats(db: &Db, chore_id: ChoreId) -> Result<Option<ChoreStats>>
         mean_overdue_days,
         median_overdue_days,
         variance_overdue_days,
+        num_snoozed,
+        num_skipped,
+        by_member,
     }))
 }
+
+/// Compare how much each member did for completions since the given time,
+/// including members who did nothing and completions attributed to nobody
+pub async fn get_household_stats(db: &Db, since: Option<Timestamp>) -> Result<Vec<MemberStats>> {
+    let chores = db.get_all_chores().await.wrap_err("Failed to get chores")?;
+    let members = db.get_members().await.wrap_err("Failed to get members")?;
+    let pauses = db.get_pauses().await.wrap_err("Failed to get vacations")?;
+
+    // lateness is measured against the whole history, not just the window
+    let mut completions: Vec<(Event, u32, bool)> = Vec::new();
+    for chore in chores.iter() {
+        let events = db.get_chore_completions(chore.id).await.wrap_err_with(|| {
+            format!(
+                "Failed to get chore completions for chore {chore}",
+                chore = chore.id
+            )
+        })?;
+        completions.extend(
+            completion_delta::calculate_event_delta_days(chore, &events, &pauses)
+                .into_iter()
+                .filter(|(event, _)| since.is_none_or(|since| event.timestamp.timestamp() >= since))
+                .map(|(event, delta)| (event.clone(), chore.effort, is_overdue(&delta))),
+        );
+    }
+
+    let total_completed = completions.len();
+    let total_effort: u64 = completions
+        .iter()
+        .map(|(_, effort, _)| u64::from(*effort))
+        .sum();
+
+    let member_ids = members
+        .iter()
+        .map(|member| Some(member.id))
+        .chain(std::iter::once(None));
+    let stats = member_ids
+        .map(|member_id| {
+            let done: Vec<&(Event, u32, bool)> = completions
+                .iter()
+                .filter(|(event, _, _)| event.member_id == member_id)
+                .collect();
+            let num_completed = done.len();
+            let effort: u64 = done.iter().map(|(_, effort, _)| u64::from(*effort)).sum();
+            let num_on_time = done.iter().filter(|(_, _, overdue)| !overdue).count();
+            MemberStats {
+                member_id,
+                num_completed,
+                share_of_completions: ratio(num_completed as f64, total_completed as f64),
+                effort,
+                share_of_effort: ratio(effort as f64, total_effort as f64),
+                on_time_rate: ratio(num_on_time as f64, num_completed as f64),
+            }
+        })
+        // only show unattributed completions if there are any
+        .filter(|stats| stats.member_id.is_some() || stats.num_completed > 0)
+        .collect();
+
+    Ok(stats)
+}
+
+fn ratio(part: f64, whole: f64) -> f64 {
+    if whole == 0.0 { 0.0 } else { part / whole }
+}
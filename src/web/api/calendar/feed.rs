@@ -0,0 +1,189 @@
+use crate::{
+    db::ChoreEvent,
+    web::{
+        AppState,
+        api::error::{ApiErrorResponse, FieldError},
+    },
+};
+use axum::{
+    extract::{Query, State, rejection::QueryRejection},
+    http::header,
+    response::{IntoResponse, Response},
+};
+use color_eyre::eyre::WrapErr;
+use jiff::{Span, Timestamp, Zoned, civil::Date};
+use serde::Deserialize;
+
+use super::ics::{self, Calendar};
+
+/// How far ahead or back the feed can look, in days
+const MAX_DAYS: u32 = 366;
+
+/// Which kind of calendar entry due dates are published as
+#[derive(Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Component {
+    /// All-day events, which every calendar app shows
+    #[default]
+    Event,
+    /// To-dos, which some calendar apps show in a task list instead
+    Todo,
+}
+
+#[derive(Deserialize)]
+pub struct CalendarFeedQuery {
+    #[serde(default)]
+    pub component: Component,
+    /// Also publish the due dates projected this many days ahead, rather
+    /// than only the next one
+    #[serde(default)]
+    pub days: u32,
+    /// Publish completions from this many days back as completed to-dos
+    #[serde(default)]
+    pub history: u32,
+}
+
+/// Publish the next and projected due dates of a chore. The next due date
+/// keeps the same UID as it moves so calendar apps update it in place
+fn write_due(
+    calendar: &mut Calendar,
+    chore_event: &ChoreEvent,
+    due: &[Zoned],
+    component: Component,
+    stamp: &str,
+) {
+    for (i, due) in due.iter().enumerate() {
+        let date = due.date();
+        let uid = if i == 0 {
+            format!("chore-{id}@chordle", id = chore_event.id)
+        } else {
+            format!(
+                "chore-{id}-{date}@chordle",
+                id = chore_event.id,
+                date = ics::date(date)
+            )
+        };
+
+        match component {
+            Component::Event => {
+                calendar.line("BEGIN:VEVENT");
+                calendar.line(&format!("UID:{uid}"));
+                calendar.line(&format!("DTSTAMP:{stamp}"));
+                calendar.line(&format!("DTSTART;VALUE=DATE:{}", ics::date(date)));
+                let end = date.tomorrow().unwrap_or(date);
+                calendar.line(&format!("DTEND;VALUE=DATE:{}", ics::date(end)));
+                calendar.line("TRANSP:TRANSPARENT");
+            }
+            Component::Todo => {
+                calendar.line("BEGIN:VTODO");
+                calendar.line(&format!("UID:{uid}"));
+                calendar.line(&format!("DTSTAMP:{stamp}"));
+                calendar.line(&format!("DUE;VALUE=DATE:{}", ics::date(date)));
+                calendar.line("STATUS:NEEDS-ACTION");
+            }
+        }
+        calendar.line(&format!("SUMMARY:{}", ics::escape(&chore_event.name)));
+        if let Some(room) = &chore_event.room {
+            calendar.line(&format!("CATEGORIES:{}", ics::escape(room)));
+        }
+        calendar.line(match component {
+            Component::Event => "END:VEVENT",
+            Component::Todo => "END:VTODO",
+        });
+    }
+}
+
+/// An iCalendar feed of when chores are due, for subscribing to from
+/// calendar apps
+pub async fn calendar_feed(
+    State(state): State<AppState>,
+    query: Result<Query<CalendarFeedQuery>, QueryRejection>,
+) -> Result<Response, ApiErrorResponse> {
+    let Query(query) = query?;
+
+    let mut errors = Vec::new();
+    if query.days > MAX_DAYS {
+        errors.push(FieldError::new(
+            "days",
+            format!("Due dates can be projected at most {MAX_DAYS} days ahead"),
+        ));
+    }
+    if query.history > MAX_DAYS {
+        errors.push(FieldError::new(
+            "history",
+            format!("Completions can be included from at most {MAX_DAYS} days back"),
+        ));
+    }
+    if !errors.is_empty() {
+        return Err(ApiErrorResponse::Validation(errors));
+    }
+
+    let now = Zoned::now();
+    let stamp = ics::timestamp(now.timestamp());
+    let mut calendar = Calendar::new("chordle");
+
+    let chore_events = state
+        .db
+        .get_all_chore_events()
+        .await
+        .wrap_err("Failed to get chores for calendar feed")?;
+    for chore_event in &chore_events {
+        let due = if query.days == 0 {
+            chore_event.projected_due(&now, Date::MAX, 1)
+        } else {
+            let until = now
+                .date()
+                .checked_add(Span::new().days(query.days))
+                .unwrap_or(Date::MAX);
+            chore_event.projected_due(&now, until, MAX_DAYS as usize)
+        };
+        write_due(&mut calendar, chore_event, &due, query.component, &stamp);
+    }
+
+    if query.history > 0 {
+        let since = now
+            .checked_sub(Span::new().days(query.history))
+            .wrap_err("Failed to calculate start of calendar feed history")?;
+        let chores = state
+            .db
+            .get_all_chores()
+            .await
+            .wrap_err("Failed to get chores for calendar feed")?;
+        let completions = state
+            .db
+            .get_completions_between(since.timestamp(), Timestamp::now())
+            .await
+            .wrap_err("Failed to get completions for calendar feed")?;
+
+        for event in &completions {
+            let Some(chore) = chores.iter().find(|chore| chore.id == event.chore_id) else {
+                continue;
+            };
+            calendar.line("BEGIN:VTODO");
+            calendar.line(&format!("UID:completion-{id}@chordle", id = event.id));
+            calendar.line(&format!("DTSTAMP:{stamp}"));
+            calendar.line(&format!("SUMMARY:{}", ics::escape(&chore.name)));
+            if let Some(room) = &chore.room {
+                calendar.line(&format!("CATEGORIES:{}", ics::escape(room)));
+            }
+            calendar.line("STATUS:COMPLETED");
+            calendar.line(&format!(
+                "COMPLETED:{}",
+                ics::timestamp(event.timestamp.timestamp())
+            ));
+            calendar.line("END:VTODO");
+        }
+    }
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "text/calendar; charset=utf-8"),
+            (
+                header::CONTENT_DISPOSITION,
+                "inline; filename=\"chordle.ics\"",
+            ),
+        ],
+        calendar.finish(),
+    )
+        .into_response())
+}
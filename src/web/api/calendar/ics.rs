@@ -0,0 +1,100 @@
+//! Just enough of iCalendar (RFC 5545) to publish chores to calendar apps
+
+use jiff::{Timestamp, civil::Date};
+
+/// The longest a content line may be, in bytes, before it is folded
+const MAX_LINE_LEN: usize = 75;
+
+/// Escape a value for use in a TEXT property
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// A date in the basic `YYYYMMDD` format, for `VALUE=DATE` properties
+pub fn date(date: Date) -> String {
+    date.strftime("%Y%m%d").to_string()
+}
+
+/// A time in UTC in the basic `YYYYMMDDTHHMMSSZ` format
+pub fn timestamp(timestamp: Timestamp) -> String {
+    timestamp.strftime("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// An iCalendar document, built up one content line at a time
+#[derive(Default)]
+pub struct Calendar {
+    contents: String,
+}
+
+impl Calendar {
+    pub fn new(name: &str) -> Calendar {
+        let mut calendar = Calendar::default();
+        calendar.line("BEGIN:VCALENDAR");
+        calendar.line("VERSION:2.0");
+        calendar.line("PRODID:-//chordle//chordle//EN");
+        calendar.line("CALSCALE:GREGORIAN");
+        calendar.line("METHOD:PUBLISH");
+        calendar.line(&format!("X-WR-CALNAME:{name}", name = escape(name)));
+        calendar
+    }
+
+    /// Add a content line, folding it so that no line is longer than 75
+    /// bytes without splitting a character
+    pub fn line(&mut self, line: &str) {
+        let mut len = 0;
+        for c in line.chars() {
+            if len + c.len_utf8() > MAX_LINE_LEN {
+                self.contents.push_str("\r\n ");
+                len = 1;
+            }
+            self.contents.push(c);
+            len += c.len_utf8();
+        }
+        self.contents.push_str("\r\n");
+    }
+
+    pub fn finish(mut self) -> String {
+        self.line("END:VCALENDAR");
+        self.contents
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_is_escaped() {
+        assert_eq!(
+            escape("Bins, recycling; compost\n"),
+            "Bins\\, recycling\\; compost\\n"
+        );
+        assert_eq!(escape("C:\\temp"), "C:\\\\temp");
+    }
+
+    #[test]
+    fn long_lines_are_folded() {
+        let mut calendar = Calendar::default();
+        let summary = format!("SUMMARY:{}", "é".repeat(60));
+        calendar.line(&summary);
+
+        let lines: Vec<&str> = calendar.contents.split("\r\n").collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines.iter().all(|line| line.len() <= MAX_LINE_LEN));
+        assert_eq!(
+            calendar.contents.replace("\r\n ", ""),
+            format!("{summary}\r\n")
+        );
+    }
+}
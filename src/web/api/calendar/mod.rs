@@ -0,0 +1,4 @@
+mod feed;
+pub use feed::calendar_feed;
+
+mod ics;
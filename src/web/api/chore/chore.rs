@@ -1,26 +1,25 @@
 use crate::{
-    db::ChoreId,
+    db::{Chore, ChoreId},
     web::{AppState, api::error::ApiErrorResponse},
 };
 use axum::{
     Json,
-    extract::{Path, State},
-    http::StatusCode,
-    response::{IntoResponse, Response},
+    extract::{Path, State, rejection::PathRejection},
 };
 use color_eyre::eyre::WrapErr;
 
 pub async fn get_chore(
-    Path(id): Path<i64>,
+    path: Result<Path<i64>, PathRejection>,
     State(state): State<AppState>,
-) -> Result<Response, ApiErrorResponse> {
+) -> Result<Json<Chore>, ApiErrorResponse> {
+    let Path(id) = path?;
     let chore = state
         .db
         .get_chore(ChoreId(id))
         .await
         .wrap_err_with(|| format!("Failed to get chore {id}",))?;
-    Ok(match chore {
-        Some(chore) => (StatusCode::OK, Json(chore)).into_response(),
-        None => (StatusCode::NOT_FOUND, Json(())).into_response(),
-    })
+    match chore {
+        Some(chore) => Ok(Json(chore)),
+        None => Err(ApiErrorResponse::not_found(format!("Chore {id} not found"))),
+    }
 }
@@ -0,0 +1,77 @@
+use crate::{
+    db::{ChoreId, ChoreStatus},
+    web::{AppState, api::error::ApiErrorResponse, updates::Update},
+};
+use axum::{
+    Json,
+    extract::{State, rejection::JsonRejection},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use color_eyre::eyre::{OptionExt, WrapErr};
+use serde::Deserialize;
+
+use super::validate::ChoreFields;
+
+#[derive(Deserialize)]
+pub struct CreateChoreReq {
+    pub name: String,
+    pub interval: String,
+    /// Defaults to a rolling schedule
+    #[serde(default)]
+    pub schedule: String,
+    /// Defaults to 1
+    #[serde(default = "default_effort")]
+    pub effort: u32,
+    /// Defaults to active
+    #[serde(default)]
+    pub status: ChoreStatus,
+    /// Defaults to no room
+    #[serde(default)]
+    pub room: Option<String>,
+    /// Defaults to no icon
+    #[serde(default)]
+    pub icon: Option<String>,
+    /// Defaults to no colour
+    #[serde(default)]
+    pub colour: Option<String>,
+}
+
+fn default_effort() -> u32 {
+    1
+}
+
+pub async fn create_chore(
+    State(state): State<AppState>,
+    req: Result<Json<CreateChoreReq>, JsonRejection>,
+) -> Result<Response, ApiErrorResponse> {
+    let Json(req) = req?;
+    let chore = super::validate::validate_chore(
+        ChoreId::default(),
+        ChoreFields {
+            name: &req.name,
+            interval: &req.interval,
+            schedule: &req.schedule,
+            effort: req.effort,
+            status: req.status,
+            room: req.room.as_deref().unwrap_or_default(),
+            icon: req.icon.as_deref().unwrap_or_default(),
+            colour: req.colour.as_deref().unwrap_or_default(),
+        },
+    )?;
+
+    let id = state
+        .db
+        .create_chore(chore)
+        .await
+        .wrap_err("Failed to create chore")?;
+    state.notify(Update::Chores);
+    let chore = state
+        .db
+        .get_chore(id)
+        .await
+        .wrap_err_with(|| format!("Failed to get newly created chore {id}"))?
+        .ok_or_eyre("Newly created chore should exist")?;
+
+    Ok((StatusCode::CREATED, Json(chore)).into_response())
+}
@@ -0,0 +1,27 @@
+use crate::{
+    db::ChoreId,
+    web::{AppState, api::error::ApiErrorResponse, updates::Update},
+};
+use axum::{
+    extract::{Path, State, rejection::PathRejection},
+    http::StatusCode,
+};
+use color_eyre::eyre::WrapErr;
+
+pub async fn delete_chore(
+    State(state): State<AppState>,
+    path: Result<Path<i64>, PathRejection>,
+) -> Result<StatusCode, ApiErrorResponse> {
+    let Path(id) = path?;
+    let deleted = state
+        .db
+        .delete_chore(ChoreId(id))
+        .await
+        .wrap_err_with(|| format!("Failed to delete chore {id}"))?;
+    if deleted {
+        state.notify(Update::Chores);
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(ApiErrorResponse::not_found(format!("Chore {id} not found")))
+    }
+}
@@ -5,5 +5,19 @@ pub use chore::get_chore;
 mod chores;
 pub use chores::get_chores;
 
+mod create;
+pub use create::create_chore;
+
+mod delete;
+pub use delete::delete_chore;
+
+mod restore;
+pub use restore::restore_chore;
+
 mod stats;
 pub use stats::get_chore_stats;
+
+mod update;
+pub use update::{patch_chore, replace_chore};
+
+mod validate;
@@ -0,0 +1,36 @@
+use crate::{
+    db::{Chore, ChoreId},
+    web::{AppState, api::error::ApiErrorResponse, updates::Update},
+};
+use axum::{
+    Json,
+    extract::{Path, State, rejection::PathRejection},
+};
+use color_eyre::eyre::{OptionExt, WrapErr};
+
+/// Take a deleted chore back out of the trash
+pub async fn restore_chore(
+    State(state): State<AppState>,
+    path: Result<Path<i64>, PathRejection>,
+) -> Result<Json<Chore>, ApiErrorResponse> {
+    let Path(id) = path?;
+    let restored = state
+        .db
+        .restore_chore(ChoreId(id))
+        .await
+        .wrap_err_with(|| format!("Failed to restore chore {id}"))?;
+    if !restored {
+        return Err(ApiErrorResponse::not_found(format!(
+            "Chore {id} not found in the trash"
+        )));
+    }
+    state.notify(Update::Chores);
+
+    let chore = state
+        .db
+        .get_chore(ChoreId(id))
+        .await
+        .wrap_err_with(|| format!("Failed to get restored chore {id}"))?
+        .ok_or_eyre("Restored chore should exist")?;
+    Ok(Json(chore))
+}
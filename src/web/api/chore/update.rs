@@ -0,0 +1,126 @@
+use crate::{
+    db::{Chore, ChoreId, ChoreStatus},
+    web::{AppState, api::error::ApiErrorResponse, updates::Update},
+};
+use axum::{
+    Json,
+    extract::{
+        Path, State,
+        rejection::{JsonRejection, PathRejection},
+    },
+};
+use color_eyre::eyre::WrapErr;
+use serde::Deserialize;
+
+use super::validate::ChoreFields;
+
+/// Body of a `PUT` request, which replaces every field of the chore
+#[derive(Deserialize)]
+pub struct ReplaceChoreReq {
+    pub name: String,
+    pub interval: String,
+    #[serde(default)]
+    pub schedule: String,
+    #[serde(default = "default_effort")]
+    pub effort: u32,
+    #[serde(default)]
+    pub status: ChoreStatus,
+    #[serde(default)]
+    pub room: Option<String>,
+    #[serde(default)]
+    pub icon: Option<String>,
+    #[serde(default)]
+    pub colour: Option<String>,
+}
+
+fn default_effort() -> u32 {
+    1
+}
+
+/// Body of a `PATCH` request, which only changes the fields that are given
+#[derive(Deserialize)]
+pub struct PatchChoreReq {
+    pub name: Option<String>,
+    pub interval: Option<String>,
+    pub schedule: Option<String>,
+    pub effort: Option<u32>,
+    pub status: Option<ChoreStatus>,
+    /// `null` or an empty string takes the chore out of its room
+    #[serde(default, deserialize_with = "crate::web::api::present")]
+    pub room: Option<Option<String>>,
+    /// `null` or an empty string removes the icon
+    #[serde(default, deserialize_with = "crate::web::api::present")]
+    pub icon: Option<Option<String>>,
+    /// `null` or an empty string removes the colour
+    #[serde(default, deserialize_with = "crate::web::api::present")]
+    pub colour: Option<Option<String>>,
+}
+
+pub async fn replace_chore(
+    State(state): State<AppState>,
+    path: Result<Path<i64>, PathRejection>,
+    req: Result<Json<ReplaceChoreReq>, JsonRejection>,
+) -> Result<Json<Chore>, ApiErrorResponse> {
+    let Path(id) = path?;
+    let Json(req) = req?;
+    let req = PatchChoreReq {
+        name: Some(req.name),
+        interval: Some(req.interval),
+        schedule: Some(req.schedule),
+        effort: Some(req.effort),
+        status: Some(req.status),
+        room: Some(req.room),
+        icon: Some(req.icon),
+        colour: Some(req.colour),
+    };
+    patch_chore(State(state), Ok(Path(id)), Ok(Json(req))).await
+}
+
+pub async fn patch_chore(
+    State(state): State<AppState>,
+    path: Result<Path<i64>, PathRejection>,
+    req: Result<Json<PatchChoreReq>, JsonRejection>,
+) -> Result<Json<Chore>, ApiErrorResponse> {
+    let Path(id) = path?;
+    let Json(req) = req?;
+    let chore = state
+        .db
+        .get_chore(ChoreId(id))
+        .await
+        .wrap_err_with(|| format!("Failed to get chore {id}"))?;
+    let Some(chore) = chore else {
+        return Err(ApiErrorResponse::not_found(format!("Chore {id} not found")));
+    };
+
+    let name = req.name.unwrap_or(chore.name);
+    let interval = req.interval.unwrap_or_else(|| chore.interval.to_string());
+    let schedule = req.schedule.unwrap_or_else(|| chore.schedule.to_string());
+    let room = req.room.unwrap_or(chore.room).unwrap_or_default();
+    let icon = req.icon.unwrap_or(chore.icon).unwrap_or_default();
+    let colour = req.colour.unwrap_or(chore.colour).unwrap_or_default();
+    let chore = super::validate::validate_chore(
+        chore.id,
+        ChoreFields {
+            name: &name,
+            interval: &interval,
+            schedule: &schedule,
+            effort: req.effort.unwrap_or(chore.effort),
+            status: req.status.unwrap_or(chore.status),
+            room: &room,
+            icon: &icon,
+            colour: &colour,
+        },
+    )?;
+
+    let updated = state
+        .db
+        .update_chore(chore.clone())
+        .await
+        .wrap_err_with(|| format!("Failed to update chore {id}"))?;
+    if !updated {
+        return Err(ApiErrorResponse::not_found(format!("Chore {id} not found")));
+    }
+    state.notify(Update::Chore(chore.id));
+
+    Ok(Json(chore))
+}
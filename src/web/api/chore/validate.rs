@@ -0,0 +1,105 @@
+use crate::{
+    db::{Chore, ChoreId, ChoreStatus},
+    web::{
+        api::error::{ApiErrorResponse, FieldError},
+        validation,
+    },
+};
+
+/// A chore's fields as they were sent, before they are validated
+pub struct ChoreFields<'a> {
+    pub name: &'a str,
+    pub interval: &'a str,
+    pub schedule: &'a str,
+    pub effort: u32,
+    pub status: ChoreStatus,
+    pub room: &'a str,
+    pub icon: &'a str,
+    pub colour: &'a str,
+}
+
+/// Validate a chore's fields the same way the manager page does, returning
+/// the chore they describe
+pub fn validate_chore(id: ChoreId, fields: ChoreFields) -> Result<Chore, ApiErrorResponse> {
+    let mut errors = Vec::new();
+    if !validation::is_valid_chore_name(fields.name) {
+        errors.push(FieldError::new(
+            "name",
+            format!(
+                "Chore names must not be empty and must be at most {max} bytes long",
+                max = validation::MAX_CHORE_NAME_LEN
+            ),
+        ));
+    }
+    let interval = validation::parse_interval(fields.interval);
+    if interval.is_none() {
+        errors.push(FieldError::new(
+            "interval",
+            "Intervals must be in ISO8601 or friendly format, e.g. \"2w 4d\"",
+        ));
+    }
+
+    let schedule = validation::parse_schedule(fields.schedule);
+    if schedule.is_none() {
+        errors.push(FieldError::new(
+            "schedule",
+            "Schedules must be empty, \"weekly: tu, th\", \"monthly: 1, -1\" or an RRULE that comes up at least once",
+        ));
+    }
+
+    if !(1..=validation::MAX_EFFORT).contains(&fields.effort) {
+        errors.push(FieldError::new(
+            "effort",
+            format!(
+                "Effort must be between 1 and {max}",
+                max = validation::MAX_EFFORT
+            ),
+        ));
+    }
+
+    let room = validation::parse_room(fields.room);
+    if room.is_none() {
+        errors.push(FieldError::new(
+            "room",
+            format!(
+                "Rooms must be at most {max} bytes long",
+                max = validation::MAX_ROOM_LEN
+            ),
+        ));
+    }
+
+    let icon = validation::parse_icon(fields.icon);
+    if icon.is_none() {
+        errors.push(FieldError::new(
+            "icon",
+            format!(
+                "Icons must be an emoji or one of: {icons}",
+                icons = validation::CHORE_ICONS.join(", ")
+            ),
+        ));
+    }
+
+    let colour = validation::parse_colour(fields.colour);
+    if colour.is_none() {
+        errors.push(FieldError::new("colour", "Colours must be in #rrggbb form"));
+    }
+
+    match (interval, schedule, room, icon, colour) {
+        (Some(interval), Some(schedule), Some(room), Some(icon), Some(colour))
+            if errors.is_empty() =>
+        {
+            Ok(Chore {
+                id,
+                name: fields.name.to_string(),
+                interval,
+                schedule,
+                effort: fields.effort,
+                status: fields.status,
+                room,
+                icon,
+                colour,
+            })
+        }
+        _ => Err(ApiErrorResponse::Validation(errors)),
+    }
+}
@@ -1,30 +1,207 @@
+use axum::Json;
 use axum::body::Body;
+use axum::extract::rejection::{JsonRejection, PathRejection, QueryRejection};
 use axum::http::{Response, StatusCode};
 use axum::response::IntoResponse;
 use color_eyre::eyre::Error as EyreError;
+use serde::Serialize;
+
+/// A problem with a single field of a request
+#[derive(Debug, Serialize)]
+pub struct FieldError {
+    pub field: &'static str,
+    pub message: String,
+}
+
+impl FieldError {
+    pub fn new<S: Into<String>>(field: &'static str, message: S) -> Self {
+        Self {
+            field,
+            message: message.into(),
+        }
+    }
+}
 
 #[derive(Debug)]
-pub struct ApiErrorResponse;
+pub enum ApiErrorResponse {
+    /// The request body could not be understood at all
+    BadRequest(String),
+    /// The request didn't come from someone who is logged in
+    Unauthorized(String),
+    /// The request came from someone who isn't allowed to make it
+    Forbidden(String),
+    /// One or more fields of the request were invalid
+    Validation(Vec<FieldError>),
+    /// The requested resource does not exist
+    NotFound(String),
+    /// The request conflicts with the current state of the data
+    Conflict(String),
+    /// Something went wrong on our end, details are logged rather than returned
+    Internal,
+}
+
+/// The JSON body sent along with every API error
+#[derive(Serialize)]
+struct ErrorBody<'e> {
+    code: &'static str,
+    message: &'e str,
+    #[serde(skip_serializing_if = "<[FieldError]>::is_empty")]
+    details: &'e [FieldError],
+}
+
+impl ApiErrorResponse {
+    pub fn unauthorized<S: Into<String>>(message: S) -> Self {
+        Self::Unauthorized(message.into())
+    }
+
+    pub fn forbidden<S: Into<String>>(message: S) -> Self {
+        Self::Forbidden(message.into())
+    }
+
+    pub fn not_found<S: Into<String>>(message: S) -> Self {
+        Self::NotFound(message.into())
+    }
+
+    pub fn conflict<S: Into<String>>(message: S) -> Self {
+        Self::Conflict(message.into())
+    }
+
+    /// A stable, machine-readable code for the error
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::BadRequest(_) => "bad_request",
+            Self::Unauthorized(_) => "unauthorized",
+            Self::Forbidden(_) => "forbidden",
+            Self::Validation(_) => "validation_failed",
+            Self::NotFound(_) => "not_found",
+            Self::Conflict(_) => "conflict",
+            Self::Internal => "internal_error",
+        }
+    }
+
+    pub fn status(&self) -> StatusCode {
+        match self {
+            Self::BadRequest(_) | Self::Validation(_) => StatusCode::BAD_REQUEST,
+            Self::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            Self::Forbidden(_) => StatusCode::FORBIDDEN,
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Conflict(_) => StatusCode::CONFLICT,
+            Self::Internal => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn message(&self) -> &str {
+        match self {
+            Self::BadRequest(message)
+            | Self::Unauthorized(message)
+            | Self::Forbidden(message)
+            | Self::NotFound(message)
+            | Self::Conflict(message) => message,
+            Self::Validation(_) => "One or more fields are invalid",
+            Self::Internal => "An internal error occurred",
+        }
+    }
+}
 
 impl std::error::Error for ApiErrorResponse {}
 impl std::fmt::Display for ApiErrorResponse {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "An error occurred")
+        write!(
+            f,
+            "{code}: {message}",
+            code = self.code(),
+            message = self.message()
+        )
     }
 }
 
 impl IntoResponse for ApiErrorResponse {
     fn into_response(self) -> Response<Body> {
-        Response::builder()
-            .status(StatusCode::INTERNAL_SERVER_ERROR)
-            .body(Body::empty())
-            .expect("Can build error response")
+        let details = match &self {
+            Self::Validation(details) => details.as_slice(),
+            _ => &[],
+        };
+        let body = ErrorBody {
+            code: self.code(),
+            message: self.message(),
+            details,
+        };
+        (self.status(), Json(body)).into_response()
     }
 }
 
 impl From<EyreError> for ApiErrorResponse {
     fn from(err: EyreError) -> Self {
         tracing::error!("API Error: {:?}", err);
-        Self
+        Self::Internal
+    }
+}
+
+impl From<JsonRejection> for ApiErrorResponse {
+    fn from(rejection: JsonRejection) -> Self {
+        Self::BadRequest(rejection.body_text())
+    }
+}
+
+impl From<PathRejection> for ApiErrorResponse {
+    fn from(rejection: PathRejection) -> Self {
+        Self::BadRequest(rejection.body_text())
+    }
+}
+
+impl From<QueryRejection> for ApiErrorResponse {
+    fn from(rejection: QueryRejection) -> Self {
+        Self::BadRequest(rejection.body_text())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn errors_map_to_statuses_and_codes() {
+        let cases = [
+            (
+                ApiErrorResponse::BadRequest("bad".to_string()),
+                StatusCode::BAD_REQUEST,
+                "bad_request",
+            ),
+            (
+                ApiErrorResponse::Validation(vec![FieldError::new("name", "empty")]),
+                StatusCode::BAD_REQUEST,
+                "validation_failed",
+            ),
+            (
+                ApiErrorResponse::unauthorized("who?"),
+                StatusCode::UNAUTHORIZED,
+                "unauthorized",
+            ),
+            (
+                ApiErrorResponse::forbidden("no"),
+                StatusCode::FORBIDDEN,
+                "forbidden",
+            ),
+            (
+                ApiErrorResponse::not_found("missing"),
+                StatusCode::NOT_FOUND,
+                "not_found",
+            ),
+            (
+                ApiErrorResponse::conflict("nope"),
+                StatusCode::CONFLICT,
+                "conflict",
+            ),
+            (
+                ApiErrorResponse::Internal,
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "internal_error",
+            ),
+        ];
+
+        for (err, status, code) in cases {
+            assert_eq!(err.status(), status);
+            assert_eq!(err.code(), code);
+        }
     }
 }
@@ -0,0 +1,123 @@
+use crate::{
+    db::{Event, EventId, EventKind, MemberId},
+    web::{
+        AppState,
+        api::error::{ApiErrorResponse, FieldError},
+        updates::Update,
+    },
+};
+use axum::{
+    Json,
+    extract::{
+        Path, State,
+        rejection::{JsonRejection, PathRejection},
+    },
+    http::StatusCode,
+};
+use color_eyre::eyre::WrapErr;
+use jiff::Zoned;
+use serde::Deserialize;
+
+use super::EventResponse;
+
+#[derive(Deserialize)]
+pub struct PatchEventReq {
+    /// When the chore was actually completed
+    pub timestamp: Option<Zoned>,
+    /// Who completed the chore, `null` to attribute it to nobody
+    #[serde(default, deserialize_with = "crate::web::api::present")]
+    pub member_id: Option<Option<MemberId>>,
+    /// When a snoozed or skipped chore is next due
+    pub until: Option<Zoned>,
+}
+
+async fn event_response(state: &AppState, id: EventId) -> Result<EventResponse, ApiErrorResponse> {
+    let event = state
+        .db
+        .get_event(id)
+        .await
+        .wrap_err_with(|| format!("Failed to get event {id}"))?
+        .ok_or_else(|| ApiErrorResponse::not_found(format!("Event {id} not found")))?;
+    let due = state
+        .db
+        .get_chore_event(event.chore_id)
+        .await
+        .wrap_err_with(|| format!("Failed to get due state for chore {}", event.chore_id))?
+        .ok_or_else(|| {
+            ApiErrorResponse::not_found(format!("Chore {} not found", event.chore_id))
+        })?;
+
+    Ok(EventResponse {
+        event,
+        due: due.into(),
+    })
+}
+
+pub async fn get_event(
+    State(state): State<AppState>,
+    path: Result<Path<i64>, PathRejection>,
+) -> Result<Json<EventResponse>, ApiErrorResponse> {
+    let Path(id) = path?;
+    Ok(Json(event_response(&state, EventId(id)).await?))
+}
+
+pub async fn patch_event(
+    State(state): State<AppState>,
+    path: Result<Path<i64>, PathRejection>,
+    req: Result<Json<PatchEventReq>, JsonRejection>,
+) -> Result<Json<EventResponse>, ApiErrorResponse> {
+    let Path(id) = path?;
+    let Json(req) = req?;
+    let event = state
+        .db
+        .get_event(EventId(id))
+        .await
+        .wrap_err_with(|| format!("Failed to get event {id}"))?
+        .ok_or_else(|| ApiErrorResponse::not_found(format!("Event {id} not found")))?;
+
+    let member_id = req.member_id.unwrap_or(event.member_id);
+    super::check_member(&state, member_id).await?;
+    if req.until.is_some() && event.kind == EventKind::Completed {
+        return Err(ApiErrorResponse::Validation(vec![FieldError::new(
+            "until",
+            "Only snoozed or skipped events can be postponed",
+        )]));
+    }
+    let chore_id = event.chore_id;
+    let event = Event {
+        timestamp: req.timestamp.unwrap_or(event.timestamp),
+        member_id,
+        until: req.until.or(event.until),
+        ..event
+    };
+
+    let updated = state
+        .db
+        .update_event(event)
+        .await
+        .wrap_err_with(|| format!("Failed to update event {id}"))?;
+    if !updated {
+        return Err(ApiErrorResponse::not_found(format!("Event {id} not found")));
+    }
+    state.notify(Update::Chore(chore_id));
+
+    Ok(Json(event_response(&state, EventId(id)).await?))
+}
+
+pub async fn delete_event(
+    State(state): State<AppState>,
+    path: Result<Path<i64>, PathRejection>,
+) -> Result<StatusCode, ApiErrorResponse> {
+    let Path(id) = path?;
+    let deleted = state
+        .db
+        .delete_event(EventId(id))
+        .await
+        .wrap_err_with(|| format!("Failed to delete event {id}"))?;
+    if deleted {
+        state.notify(Update::Chores);
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(ApiErrorResponse::not_found(format!("Event {id} not found")))
+    }
+}
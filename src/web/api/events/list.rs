@@ -0,0 +1,129 @@
+use crate::{
+    db::{ChoreId, EventCursor, EventFilter, EventOrder, EventPage},
+    web::{
+        AppState,
+        api::error::{ApiErrorResponse, FieldError},
+    },
+};
+use axum::{
+    Json,
+    extract::{
+        Path, Query, State,
+        rejection::{PathRejection, QueryRejection},
+    },
+};
+use color_eyre::eyre::WrapErr;
+use jiff::Timestamp;
+use serde::Deserialize;
+
+const DEFAULT_LIMIT: u32 = 50;
+const MAX_LIMIT: u32 = 500;
+
+#[derive(Deserialize)]
+pub struct ListEventsQuery {
+    /// Only list events at or after this time
+    pub since: Option<String>,
+    /// Only list events before this time
+    pub until: Option<String>,
+    pub limit: Option<u32>,
+    /// The `next_cursor` of the previous page
+    pub cursor: Option<String>,
+    #[serde(default)]
+    pub order: EventOrder,
+}
+
+impl ListEventsQuery {
+    fn into_filter(self, chore_id: Option<ChoreId>) -> Result<EventFilter, ApiErrorResponse> {
+        let mut errors = Vec::new();
+
+        let mut parse_time = |field: &'static str, value: Option<String>| {
+            value.and_then(|value| match value.parse::<Timestamp>() {
+                Ok(timestamp) => Some(timestamp),
+                Err(_) => {
+                    errors.push(FieldError::new(
+                        field,
+                        "Times must be RFC 3339 timestamps, e.g. \"2025-03-16T20:24:36-06:00\"",
+                    ));
+                    None
+                }
+            })
+        };
+        let since = parse_time("since", self.since);
+        let until = parse_time("until", self.until);
+
+        let limit = self.limit.unwrap_or(DEFAULT_LIMIT);
+        if limit == 0 || limit > MAX_LIMIT {
+            errors.push(FieldError::new(
+                "limit",
+                format!("The limit must be between 1 and {MAX_LIMIT}"),
+            ));
+        }
+
+        let cursor = self
+            .cursor
+            .and_then(|cursor| match cursor.parse::<EventCursor>() {
+                Ok(cursor) => Some(cursor),
+                Err(_) => {
+                    errors.push(FieldError::new(
+                        "cursor",
+                        "The cursor must be the `next_cursor` of a previous page",
+                    ));
+                    None
+                }
+            });
+
+        if !errors.is_empty() {
+            return Err(ApiErrorResponse::Validation(errors));
+        }
+
+        Ok(EventFilter {
+            chore_id,
+            since,
+            until,
+            order: self.order,
+            limit,
+            cursor,
+        })
+    }
+}
+
+pub async fn list_events(
+    State(state): State<AppState>,
+    query: Result<Query<ListEventsQuery>, QueryRejection>,
+) -> Result<Json<EventPage>, ApiErrorResponse> {
+    let Query(query) = query?;
+    let filter = query.into_filter(None)?;
+
+    let page = state
+        .db
+        .get_events(&filter)
+        .await
+        .wrap_err("Failed to list events")?;
+    Ok(Json(page))
+}
+
+pub async fn list_chore_events(
+    State(state): State<AppState>,
+    path: Result<Path<i64>, PathRejection>,
+    query: Result<Query<ListEventsQuery>, QueryRejection>,
+) -> Result<Json<EventPage>, ApiErrorResponse> {
+    let Path(id) = path?;
+    let Query(query) = query?;
+    let filter = query.into_filter(Some(ChoreId(id)))?;
+
+    let chore = state
+        .db
+        .get_chore(ChoreId(id))
+        .await
+        .wrap_err_with(|| format!("Failed to get chore {id}"))?;
+    if chore.is_none() {
+        return Err(ApiErrorResponse::not_found(format!("Chore {id} not found")));
+    }
+
+    let page = state
+        .db
+        .get_events(&filter)
+        .await
+        .wrap_err_with(|| format!("Failed to list events for chore {id}"))?;
+    Ok(Json(page))
+}
@@ -0,0 +1,76 @@
+use crate::{
+    db::{ChoreEvent, ChoreId, Event, MemberId},
+    web::{
+        AppState,
+        api::error::{ApiErrorResponse, FieldError},
+    },
+};
+use color_eyre::eyre::WrapErr;
+use jiff::Zoned;
+use serde::Serialize;
+
+mod event;
+pub use event::{delete_event, get_event, patch_event};
+
+mod list;
+pub use list::{list_chore_events, list_events};
+
+mod postpone;
+pub use postpone::{skip_chore, snooze_chore};
+
+mod record;
+pub use record::record_event;
+
+mod redo;
+pub use redo::redo_event;
+
+mod undo;
+pub use undo::undo_event;
+
+/// When a chore was last done and when it is next due
+#[derive(Serialize)]
+pub struct DueState {
+    pub chore_id: ChoreId,
+    pub last_completed: Option<Zoned>,
+    pub next_due: Option<Zoned>,
+}
+
+impl From<ChoreEvent> for DueState {
+    fn from(chore_event: ChoreEvent) -> Self {
+        Self {
+            chore_id: chore_event.id,
+            next_due: chore_event.next_due(),
+            last_completed: chore_event.timestamp,
+        }
+    }
+}
+
+/// The event that was affected by a request, along with the new due state
+/// of its chore
+#[derive(Serialize)]
+pub struct EventResponse {
+    pub event: Event,
+    pub due: DueState,
+}
+
+/// Make sure that a member an event is attributed to exists
+async fn check_member(
+    state: &AppState,
+    member_id: Option<MemberId>,
+) -> Result<(), ApiErrorResponse> {
+    let Some(member_id) = member_id else {
+        return Ok(());
+    };
+    let member = state
+        .db
+        .get_member(member_id)
+        .await
+        .wrap_err_with(|| format!("Failed to get member {member_id}"))?;
+    match member {
+        Some(_) => Ok(()),
+        None => Err(ApiErrorResponse::Validation(vec![FieldError::new(
+            "member_id",
+            format!("Member {member_id} not found"),
+        )])),
+    }
+}
@@ -0,0 +1,123 @@
+use crate::{
+    db::{ChoreId, EventKind, MemberId, User, UserId},
+    web::{
+        AppState,
+        api::error::{ApiErrorResponse, FieldError},
+        updates::Update,
+    },
+};
+use axum::{
+    Extension, Json,
+    extract::{
+        Path, State,
+        rejection::{JsonRejection, PathRejection},
+    },
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use color_eyre::eyre::WrapErr;
+use jiff::Zoned;
+use serde::Deserialize;
+
+use super::EventResponse;
+
+#[derive(Deserialize)]
+pub struct SnoozeReq {
+    /// When the chore should next be due
+    pub until: Zoned,
+    /// Who snoozed the chore
+    pub member_id: Option<MemberId>,
+}
+
+#[derive(Deserialize)]
+pub struct SkipReq {
+    /// Who skipped the chore
+    pub member_id: Option<MemberId>,
+}
+
+pub async fn snooze_chore(
+    State(state): State<AppState>,
+    path: Result<Path<i64>, PathRejection>,
+    user: Option<Extension<User>>,
+    req: Result<Json<SnoozeReq>, JsonRejection>,
+) -> Result<Response, ApiErrorResponse> {
+    let Path(id) = path?;
+    let Json(req) = req?;
+    if req.until <= Zoned::now() {
+        return Err(ApiErrorResponse::Validation(vec![FieldError::new(
+            "until",
+            "Chores can only be snoozed until some time in the future",
+        )]));
+    }
+    postpone(
+        &state,
+        ChoreId(id),
+        EventKind::Snoozed,
+        Some(req.until),
+        req.member_id,
+        user.map(|Extension(user)| user.id),
+    )
+    .await
+}
+
+pub async fn skip_chore(
+    State(state): State<AppState>,
+    path: Result<Path<i64>, PathRejection>,
+    user: Option<Extension<User>>,
+    req: Result<Option<Json<SkipReq>>, JsonRejection>,
+) -> Result<Response, ApiErrorResponse> {
+    let Path(id) = path?;
+    let member_id = req?.and_then(|Json(req)| req.member_id);
+    postpone(
+        &state,
+        ChoreId(id),
+        EventKind::Skipped,
+        None,
+        member_id,
+        user.map(|Extension(user)| user.id),
+    )
+    .await
+}
+
+/// Postpone a chore until the given time, or its next occurrence if `None`
+async fn postpone(
+    state: &AppState,
+    chore_id: ChoreId,
+    kind: EventKind,
+    until: Option<Zoned>,
+    member_id: Option<MemberId>,
+    user_id: Option<UserId>,
+) -> Result<Response, ApiErrorResponse> {
+    let chore_event = state
+        .db
+        .get_chore_event(chore_id)
+        .await
+        .wrap_err_with(|| format!("Failed to get chore {chore_id}"))?
+        .ok_or_else(|| ApiErrorResponse::not_found(format!("Chore {chore_id} not found")))?;
+    super::check_member(state, member_id).await?;
+
+    let until = until.unwrap_or_else(|| chore_event.next_due_when_skipped(&Zoned::now()));
+    let event = state
+        .db
+        .postpone_chore(chore_id, kind, until, member_id, user_id)
+        .await
+        .wrap_err_with(|| format!("Failed to postpone chore {chore_id}"))?
+        .ok_or_else(|| ApiErrorResponse::not_found(format!("Chore {chore_id} not found")))?;
+    state.notify(Update::Chore(chore_id));
+
+    let due = state
+        .db
+        .get_chore_event(chore_id)
+        .await
+        .wrap_err_with(|| format!("Failed to get due state for chore {chore_id}"))?
+        .ok_or_else(|| ApiErrorResponse::not_found(format!("Chore {chore_id} not found")))?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(EventResponse {
+            event,
+            due: due.into(),
+        }),
+    )
+        .into_response())
+}
@@ -0,0 +1,88 @@
+use crate::{
+    db::{ChoreId, MemberId, User},
+    web::{
+        AppState,
+        api::error::{ApiErrorResponse, FieldError},
+        updates::Update,
+        validation::{self, MAX_IDEMPOTENCY_KEY_LEN},
+    },
+};
+use axum::{
+    Extension, Json,
+    extract::{
+        Path, State,
+        rejection::{JsonRejection, PathRejection},
+    },
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use color_eyre::eyre::WrapErr;
+use jiff::Zoned;
+use serde::Deserialize;
+
+use super::EventResponse;
+
+#[derive(Deserialize)]
+pub struct RecordEventReq {
+    /// When the chore was completed, defaulting to now
+    pub timestamp: Option<Zoned>,
+    /// Who completed the chore
+    pub member_id: Option<MemberId>,
+    /// Chosen by the client so that sending the same completion again, e.g.
+    /// after a dropped connection, doesn't record it twice
+    pub idempotency_key: Option<String>,
+}
+
+pub async fn record_event(
+    State(state): State<AppState>,
+    path: Result<Path<i64>, PathRejection>,
+    user: Option<Extension<User>>,
+    req: Result<Option<Json<RecordEventReq>>, JsonRejection>,
+) -> Result<Response, ApiErrorResponse> {
+    let Path(id) = path?;
+    let req = req?;
+    let chore_id = ChoreId(id);
+    let (timestamp, member_id, idempotency_key) = match req {
+        Some(Json(req)) => (req.timestamp, req.member_id, req.idempotency_key),
+        None => (None, None, None),
+    };
+    if idempotency_key
+        .as_deref()
+        .is_some_and(|key| !validation::is_valid_idempotency_key(key))
+    {
+        return Err(ApiErrorResponse::Validation(vec![FieldError::new(
+            "idempotency_key",
+            format!("Idempotency keys must be 1 to {MAX_IDEMPOTENCY_KEY_LEN} bytes long"),
+        )]));
+    }
+    super::check_member(&state, member_id).await?;
+    let event = state
+        .db
+        .record_chore_event_when(
+            chore_id,
+            timestamp.unwrap_or_else(Zoned::now),
+            member_id,
+            user.map(|Extension(user)| user.id),
+            idempotency_key.as_deref(),
+        )
+        .await
+        .wrap_err_with(|| format!("Failed to record event for chore {id}"))?
+        .ok_or_else(|| ApiErrorResponse::not_found(format!("Chore {id} not found")))?;
+    state.notify(Update::Chore(chore_id));
+
+    let due = state
+        .db
+        .get_chore_event(chore_id)
+        .await
+        .wrap_err_with(|| format!("Failed to get due state for chore {id}"))?
+        .ok_or_else(|| ApiErrorResponse::not_found(format!("Chore {id} not found")))?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(EventResponse {
+            event,
+            due: due.into(),
+        }),
+    )
+        .into_response())
+}
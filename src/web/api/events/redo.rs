@@ -0,0 +1,33 @@
+use crate::web::{AppState, api::error::ApiErrorResponse, updates::Update};
+use axum::{Json, extract::State};
+use color_eyre::eyre::WrapErr;
+
+use super::EventResponse;
+
+pub async fn redo_event(
+    State(state): State<AppState>,
+) -> Result<Json<EventResponse>, ApiErrorResponse> {
+    let event = state
+        .db
+        .redo_chore_event()
+        .await
+        .wrap_err("Failed to redo event")?;
+    let Some(event) = event else {
+        return Err(ApiErrorResponse::conflict("There are no events to redo"));
+    };
+    state.notify(Update::Chore(event.chore_id));
+
+    let due = state
+        .db
+        .get_chore_event(event.chore_id)
+        .await
+        .wrap_err_with(|| format!("Failed to get due state for chore {}", event.chore_id))?
+        .ok_or_else(|| {
+            ApiErrorResponse::not_found(format!("Chore {} not found", event.chore_id))
+        })?;
+
+    Ok(Json(EventResponse {
+        event,
+        due: due.into(),
+    }))
+}
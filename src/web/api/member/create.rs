@@ -0,0 +1,33 @@
+use crate::{
+    db::Member,
+    web::{AppState, api::error::ApiErrorResponse},
+};
+use axum::{
+    Json,
+    extract::{State, rejection::JsonRejection},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use color_eyre::eyre::WrapErr;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct CreateMemberReq {
+    pub name: String,
+}
+
+pub async fn create_member(
+    State(state): State<AppState>,
+    req: Result<Json<CreateMemberReq>, JsonRejection>,
+) -> Result<Response, ApiErrorResponse> {
+    let Json(req) = req?;
+    super::validate::validate_member(&req.name)?;
+
+    let id = state
+        .db
+        .create_member(&req.name)
+        .await
+        .wrap_err("Failed to create member")?;
+
+    Ok((StatusCode::CREATED, Json(Member { id, name: req.name })).into_response())
+}
@@ -0,0 +1,28 @@
+use crate::{
+    db::MemberId,
+    web::{AppState, api::error::ApiErrorResponse},
+};
+use axum::{
+    extract::{Path, State, rejection::PathRejection},
+    http::StatusCode,
+};
+use color_eyre::eyre::WrapErr;
+
+pub async fn delete_member(
+    State(state): State<AppState>,
+    path: Result<Path<i64>, PathRejection>,
+) -> Result<StatusCode, ApiErrorResponse> {
+    let Path(id) = path?;
+    let deleted = state
+        .db
+        .delete_member(MemberId(id))
+        .await
+        .wrap_err_with(|| format!("Failed to delete member {id}"))?;
+    if deleted {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(ApiErrorResponse::not_found(format!(
+            "Member {id} not found"
+        )))
+    }
+}
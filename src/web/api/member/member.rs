@@ -0,0 +1,27 @@
+use crate::{
+    db::{Member, MemberId},
+    web::{AppState, api::error::ApiErrorResponse},
+};
+use axum::{
+    Json,
+    extract::{Path, State, rejection::PathRejection},
+};
+use color_eyre::eyre::WrapErr;
+
+pub async fn get_member(
+    path: Result<Path<i64>, PathRejection>,
+    State(state): State<AppState>,
+) -> Result<Json<Member>, ApiErrorResponse> {
+    let Path(id) = path?;
+    let member = state
+        .db
+        .get_member(MemberId(id))
+        .await
+        .wrap_err_with(|| format!("Failed to get member {id}"))?;
+    match member {
+        Some(member) => Ok(Json(member)),
+        None => Err(ApiErrorResponse::not_found(format!(
+            "Member {id} not found"
+        ))),
+    }
+}
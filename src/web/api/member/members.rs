@@ -0,0 +1,17 @@
+use crate::{
+    db::Member,
+    web::{AppState, api::error::ApiErrorResponse},
+};
+use axum::{Json, extract::State};
+use color_eyre::eyre::WrapErr;
+
+pub async fn get_members(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<Member>>, ApiErrorResponse> {
+    let members = state
+        .db
+        .get_members()
+        .await
+        .wrap_err("Failed to get members")?;
+    Ok(Json(members))
+}
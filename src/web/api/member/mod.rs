@@ -0,0 +1,17 @@
+#[allow(clippy::module_inception)]
+mod member;
+pub use member::get_member;
+
+mod members;
+pub use members::get_members;
+
+mod create;
+pub use create::create_member;
+
+mod delete;
+pub use delete::delete_member;
+
+mod update;
+pub use update::update_member;
+
+mod validate;
@@ -0,0 +1,46 @@
+use crate::{
+    db::{Member, MemberId},
+    web::{AppState, api::error::ApiErrorResponse},
+};
+use axum::{
+    Json,
+    extract::{
+        Path, State,
+        rejection::{JsonRejection, PathRejection},
+    },
+};
+use color_eyre::eyre::WrapErr;
+use serde::Deserialize;
+
+/// Body of a `PUT` or `PATCH` request, a member only has a name to change
+#[derive(Deserialize)]
+pub struct UpdateMemberReq {
+    pub name: String,
+}
+
+pub async fn update_member(
+    State(state): State<AppState>,
+    path: Result<Path<i64>, PathRejection>,
+    req: Result<Json<UpdateMemberReq>, JsonRejection>,
+) -> Result<Json<Member>, ApiErrorResponse> {
+    let Path(id) = path?;
+    let Json(req) = req?;
+    super::validate::validate_member(&req.name)?;
+
+    let member = Member {
+        id: MemberId(id),
+        name: req.name,
+    };
+    let updated = state
+        .db
+        .update_member(member.clone())
+        .await
+        .wrap_err_with(|| format!("Failed to update member {id}"))?;
+    if !updated {
+        return Err(ApiErrorResponse::not_found(format!(
+            "Member {id} not found"
+        )));
+    }
+
+    Ok(Json(member))
+}
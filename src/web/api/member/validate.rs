@@ -0,0 +1,19 @@
+use crate::web::{
+    api::error::{ApiErrorResponse, FieldError},
+    validation,
+};
+
+/// Validate a member's name the same way the manager page does
+pub fn validate_member(name: &str) -> Result<(), ApiErrorResponse> {
+    if validation::is_valid_member_name(name) {
+        Ok(())
+    } else {
+        Err(ApiErrorResponse::Validation(vec![FieldError::new(
+            "name",
+            format!(
+                "Member names must not be blank and must be at most {max} bytes long",
+                max = validation::MAX_MEMBER_NAME_LEN
+            ),
+        )]))
+    }
+}
@@ -1,38 +1,122 @@
 use std::any::Any;
 
-use super::AppState;
+use super::{
+    AppState,
+    auth::api_token::{Access, require_access},
+};
+use crate::db::{Role, Scope};
 use axum::{
     Router,
     body::Body,
-    http::{Response, StatusCode},
-    routing::get,
+    http::Response,
+    middleware,
+    response::IntoResponse,
+    routing::{get, patch, post, put},
 };
+use error::ApiErrorResponse;
+use serde::{Deserialize, Deserializer};
 use tower_http::catch_panic::CatchPanicLayer;
 
+mod calendar;
 mod chore;
-mod error;
+pub mod error;
+mod events;
 mod health_check;
+mod member;
 mod parse_span;
 
+/// Reading anything, for anyone logged in
+const READ: Access = Access {
+    role: Role::Viewer,
+    scope: Scope::ChoresRead,
+};
+/// Pressing the buttons on the home page
+const RECORD: Access = Access {
+    role: Role::Member,
+    scope: Scope::EventsWrite,
+};
+/// Rewriting history, which a token that only presses buttons shouldn't do
+const EDIT_EVENTS: Access = Access {
+    role: Role::Admin,
+    scope: Scope::ChoresWrite,
+};
+/// Managing chores and members
+const EDIT_CHORES: Access = Access {
+    role: Role::Admin,
+    scope: Scope::ChoresWrite,
+};
+
 pub fn routes() -> Router<AppState> {
-    Router::new()
-        .route("/health", get(health_check::health_check))
+    let read = Router::new()
         .route("/parse_span", get(parse_span::parse_span))
+        .route("/calendar.ics", get(calendar::calendar_feed))
         .route("/chore/{id}", get(chore::get_chore))
+        .route("/chore/{id}/events", get(events::list_chore_events))
         .route("/chore/{id}/stats", get(chore::get_chore_stats))
         .route("/chores", get(chore::get_chores))
+        .route("/member/{id}", get(member::get_member))
+        .route("/members", get(member::get_members))
+        .route("/events", get(events::list_events))
+        .route("/events/{id}", get(events::get_event))
+        .route_layer(middleware::from_fn_with_state(READ, require_access));
+
+    let record = Router::new()
+        .route("/chore/{id}/events", post(events::record_event))
+        .route("/chore/{id}/snooze", post(events::snooze_chore))
+        .route("/chore/{id}/skip", post(events::skip_chore))
+        .route("/events/undo", post(events::undo_event))
+        .route("/events/redo", post(events::redo_event))
+        .route_layer(middleware::from_fn_with_state(RECORD, require_access));
+
+    let edit_events = Router::new()
+        .route(
+            "/events/{id}",
+            patch(events::patch_event).delete(events::delete_event),
+        )
+        .route_layer(middleware::from_fn_with_state(EDIT_EVENTS, require_access));
+
+    let edit_chores = Router::new()
+        .route(
+            "/chore/{id}",
+            put(chore::replace_chore)
+                .patch(chore::patch_chore)
+                .delete(chore::delete_chore),
+        )
+        .route("/chore/{id}/restore", post(chore::restore_chore))
+        .route("/chores", post(chore::create_chore))
+        .route(
+            "/member/{id}",
+            put(member::update_member)
+                .patch(member::update_member)
+                .delete(member::delete_member),
+        )
+        .route("/members", post(member::create_member))
+        .route_layer(middleware::from_fn_with_state(EDIT_CHORES, require_access));
+
+    Router::new()
+        .route("/health", get(health_check::health_check))
+        .merge(read)
+        .merge(record)
+        .merge(edit_events)
+        .merge(edit_chores)
         .layer(CatchPanicLayer::custom(handle_panic))
         .fallback(handler_404)
 }
 
 fn handle_panic(_err: Box<dyn Any + Send + 'static>) -> Response<Body> {
     // err can be ignored because color_eyre will log it
-    Response::builder()
-        .status(StatusCode::INTERNAL_SERVER_ERROR)
-        .body(Body::empty())
-        .expect("Internal Server Error response should be valid")
+    ApiErrorResponse::Internal.into_response()
+}
+
+async fn handler_404() -> ApiErrorResponse {
+    ApiErrorResponse::not_found("No such API endpoint")
 }
 
-async fn handler_404() -> StatusCode {
-    StatusCode::NOT_FOUND
+/// Tell a field that is `null` apart from one that is missing
+fn present<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
 }
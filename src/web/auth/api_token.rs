@@ -0,0 +1,100 @@
+use axum::{
+    Extension,
+    extract::{Request, State},
+    http::header,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::{
+    db::{ApiToken, Role, Scope},
+    web::api::error::ApiErrorResponse,
+};
+
+/// What an API router needs: a session with at least `role`, or an API
+/// token with `scope`
+#[derive(Clone, Copy, Debug)]
+pub struct Access {
+    pub role: Role,
+    pub scope: Scope,
+}
+
+/// The API token a request was sent with, from its `Authorization: Bearer`
+/// header. Calendar apps can't send headers, so the calendar feed also takes
+/// it from a `token` query parameter
+pub fn bearer_token(request: &Request) -> Option<&str> {
+    let header = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map(str::trim);
+    if header.is_some() {
+        return header;
+    }
+
+    if request.uri().path() != "/api/calendar.ics" {
+        return None;
+    }
+    request
+        .uri()
+        .query()?
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("token="))
+}
+
+/// Middleware for API routers, layered on with
+/// `from_fn_with_state(Access { .. }, require_access)`
+pub async fn require_access(
+    State(access): State<Access>,
+    role: Role,
+    token: Option<Extension<ApiToken>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    match token {
+        Some(Extension(token)) if !token.has_scope(access.scope) => {
+            ApiErrorResponse::forbidden(format!(
+                "This needs a token with the {scope} scope",
+                scope = access.scope.as_str()
+            ))
+            .into_response()
+        }
+        None if role < access.role => ApiErrorResponse::forbidden(format!(
+            "This needs the {role} role",
+            role = access.role.as_str()
+        ))
+        .into_response(),
+        _ => next.run(request).await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokens_come_from_the_header_or_the_calendar_query() {
+        let request = |uri: &str, authorization: Option<&str>| {
+            let mut builder = Request::builder().uri(uri);
+            if let Some(authorization) = authorization {
+                builder = builder.header(header::AUTHORIZATION, authorization);
+            }
+            builder.body(axum::body::Body::empty()).unwrap()
+        };
+
+        assert_eq!(
+            bearer_token(&request("/api/chores", Some("Bearer abc"))),
+            Some("abc")
+        );
+        assert_eq!(
+            bearer_token(&request("/api/chores", Some("Basic abc"))),
+            None
+        );
+        assert_eq!(bearer_token(&request("/api/chores?token=abc", None)), None);
+        assert_eq!(
+            bearer_token(&request("/api/calendar.ics?days=7&token=abc", None)),
+            Some("abc")
+        );
+    }
+}
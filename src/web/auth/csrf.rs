@@ -0,0 +1,215 @@
+use axum::{
+    body::{self, Body},
+    extract::{Request, State},
+    http::{HeaderMap, Method, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use axum_extra::extract::{
+    CookieJar,
+    cookie::{Cookie, SameSite},
+};
+use maud::{Markup, Render, html};
+use serde::Deserialize;
+
+use crate::web::{AppState, api::error::ApiErrorResponse, ui};
+
+use super::{LoginMethod, new_token, session::SESSION_LENGTH};
+
+/// The cookie holding a browser's CSRF token
+pub const CSRF_COOKIE: &str = "csrf";
+
+/// The form field pages send the CSRF token back in
+const CSRF_FIELD: &str = "csrf_token";
+
+/// The header scripts can send the CSRF token in instead
+const CSRF_HEADER: &str = "x-csrf-token";
+
+/// The most form data that is read while looking for the token, matching
+/// axum's default body limit
+const MAX_FORM_LEN: usize = 2 * 1024 * 1024;
+
+/// A browser's token for proving a form was posted from one of chordle's own
+/// pages, which other sites can't read it from. Rendering it gives the
+/// hidden field that every form posting to chordle needs
+#[derive(Clone, Debug)]
+pub struct CsrfToken(String);
+
+impl Render for CsrfToken {
+    fn render(&self) -> Markup {
+        html! {
+            input type="hidden" name=(CSRF_FIELD) value=(self.0);
+        }
+    }
+}
+
+#[cfg(test)]
+impl CsrfToken {
+    pub fn new(token: String) -> CsrfToken {
+        CsrfToken(token)
+    }
+}
+
+#[derive(Deserialize)]
+struct CsrfForm {
+    csrf_token: Option<String>,
+}
+
+/// The cookie holding a CSRF token. It is replaced whenever someone logs in
+/// or out, so each session gets its own
+pub fn csrf_cookie(token: String, secure: bool) -> Cookie<'static> {
+    Cookie::build((CSRF_COOKIE, token))
+        .path("/")
+        .http_only(true)
+        .secure(secure)
+        .same_site(SameSite::Strict)
+        .max_age(time::Duration::seconds(SESSION_LENGTH.as_secs()))
+        .build()
+}
+
+/// Compare tokens in constant time, so how long it takes doesn't give away
+/// how much of a guess was right
+fn tokens_match(expected: &str, submitted: &str) -> bool {
+    expected.len() == submitted.len()
+        && expected
+            .bytes()
+            .zip(submitted.bytes())
+            .fold(0, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+fn is_form(headers: &HeaderMap) -> bool {
+    headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/x-www-form-urlencoded"))
+}
+
+fn is_html(headers: &HeaderMap) -> bool {
+    headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("text/html"))
+}
+
+/// Whether a request has to carry the CSRF token. Pages always post with it,
+/// but the API only needs it when logged in with a session cookie, the one
+/// credential browsers send along when another site makes the request
+fn needs_token(method: &Method, is_api: bool, login: Option<LoginMethod>) -> bool {
+    let is_safe = matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS);
+    !is_safe && (!is_api || login == Some(LoginMethod::Session))
+}
+
+/// Middleware that rejects posts without the browser's CSRF token, so other
+/// sites can't post to chordle on someone's behalf. Pages send it back in a
+/// form field, and API requests logged in with a session cookie in the
+/// `x-csrf-token` header. The token is made available to handlers as a
+/// [`CsrfToken`] extension, and set as a cookie alongside the first page that
+/// needs it
+pub async fn require_csrf_token(
+    State(app_state): State<AppState>,
+    jar: CookieJar,
+    request: Request,
+    next: Next,
+) -> Response {
+    let (token, is_new) = match jar.get(CSRF_COOKIE) {
+        Some(cookie) if !cookie.value().is_empty() => (cookie.value().to_string(), false),
+        _ => (new_token(), true),
+    };
+
+    let is_api = request.uri().path().starts_with("/api/");
+    let login = request.extensions().get::<LoginMethod>().copied();
+    let mut request = if !needs_token(request.method(), is_api, login) {
+        request
+    } else if is_api {
+        let submitted = request
+            .headers()
+            .get(CSRF_HEADER)
+            .and_then(|value| value.to_str().ok());
+        if is_new || !submitted.is_some_and(|submitted| tokens_match(&token, submitted)) {
+            tracing::warn!(
+                "Rejecting {method} {path} without a valid CSRF token",
+                method = request.method(),
+                path = request.uri().path()
+            );
+            return ApiErrorResponse::forbidden(
+                "Send the csrf cookie in the x-csrf-token header when logged in with a session",
+            )
+            .into_response();
+        }
+        request
+    } else {
+        let (parts, body) = request.into_parts();
+        let (submitted, body) = if let Some(value) = parts.headers.get(CSRF_HEADER) {
+            (value.to_str().ok().map(str::to_string), body)
+        } else if is_form(&parts.headers) {
+            let bytes = match body::to_bytes(body, MAX_FORM_LEN).await {
+                Ok(bytes) => bytes,
+                Err(err) => {
+                    tracing::warn!("Failed to read form while checking CSRF token: {err}");
+                    return ui::forbidden_page().into_response();
+                }
+            };
+            let submitted = serde_urlencoded::from_bytes::<CsrfForm>(&bytes)
+                .ok()
+                .and_then(|form| form.csrf_token);
+            (submitted, Body::from(bytes))
+        } else {
+            (None, body)
+        };
+
+        if is_new || !submitted.is_some_and(|submitted| tokens_match(&token, &submitted)) {
+            tracing::warn!(
+                "Rejecting {method} {path} without a valid CSRF token",
+                method = parts.method,
+                path = parts.uri.path()
+            );
+            return ui::forbidden_page().into_response();
+        }
+
+        Request::from_parts(parts, body)
+    };
+
+    request.extensions_mut().insert(CsrfToken(token.clone()));
+    let response = next.run(request).await;
+    if is_new && is_html(response.headers()) {
+        let jar = CookieJar::new().add(csrf_cookie(token, app_state.auth.secure_cookies));
+        (jar, response).into_response()
+    } else {
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_the_same_token_matches() {
+        let token = new_token();
+        assert!(tokens_match(&token, &token.clone()));
+        assert!(!tokens_match(&token, &new_token()));
+        assert!(!tokens_match(&token, &token[1..]));
+        assert!(!tokens_match(&token, ""));
+    }
+
+    #[test]
+    fn only_session_logins_need_the_token_for_the_api() {
+        let post = Method::POST;
+        assert!(needs_token(&post, true, Some(LoginMethod::Session)));
+        assert!(!needs_token(&post, true, Some(LoginMethod::ApiToken)));
+        assert!(!needs_token(&post, true, Some(LoginMethod::Proxy)));
+        // open mode, before any user exists
+        assert!(!needs_token(&post, true, None));
+        assert!(!needs_token(&Method::GET, true, Some(LoginMethod::Session)));
+    }
+
+    #[test]
+    fn pages_always_need_the_token_to_post() {
+        for login in [None, Some(LoginMethod::Proxy), Some(LoginMethod::Session)] {
+            assert!(needs_token(&Method::POST, false, login));
+            assert!(needs_token(&Method::DELETE, false, login));
+            assert!(!needs_token(&Method::GET, false, login));
+        }
+    }
+}
@@ -0,0 +1,240 @@
+use std::{
+    convert::Infallible,
+    net::{IpAddr, SocketAddr},
+};
+
+use axum::{
+    extract::{ConnectInfo, FromRequestParts, Request, State},
+    http::{HeaderMap, Method, request::Parts},
+    middleware::Next,
+    response::{IntoResponse, Redirect, Response},
+};
+use axum_extra::extract::CookieJar;
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use color_eyre::{Result, eyre::WrapErr};
+use rand::{RngCore, rngs::OsRng};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    db::{Db, Role, User},
+    web::{
+        AppState,
+        api::error::ApiErrorResponse,
+        ui::{self, error::ErrorResponse},
+    },
+};
+
+pub mod api_token;
+pub mod csrf;
+pub mod password;
+pub mod proxy;
+pub mod session;
+
+/// A new random token for a session or API token, safe to put in a cookie
+/// or header
+pub fn new_token() -> String {
+    let mut bytes = [0; 32];
+    OsRng.fill_bytes(&mut bytes);
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+/// What a token is stored as, so that the database alone can't be used to
+/// log in
+pub fn hash_token(token: &str) -> String {
+    Sha256::digest(token.as_bytes())
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+/// How people log in
+#[derive(Clone, Debug)]
+pub struct AuthConfig {
+    /// Only send the session cookie over HTTPS
+    pub secure_cookies: bool,
+    /// Who to trust to say who is logged in, if anyone
+    pub proxy: Option<proxy::TrustedProxy>,
+}
+
+/// How a request was logged in, made available to handlers as an extension
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LoginMethod {
+    /// An API token in the `Authorization` header
+    ApiToken,
+    /// A username header from a trusted proxy
+    Proxy,
+    /// A session cookie, which browsers send along on their own
+    Session,
+}
+
+/// Paths anyone can fetch without logging in: the health check, the login
+/// page and what it needs to look right
+fn is_public(path: &str) -> bool {
+    matches!(
+        path,
+        "/api/health"
+            | "/styles.css"
+            | "/manifest.json"
+            | "/service-worker.js"
+            | "/icon.png"
+            | "/favicon.ico"
+    ) || path == ui::LOGIN_URI
+        || path.starts_with("/icons/")
+}
+
+/// The user a request's session cookie belongs to, if it is still valid
+async fn session_user(db: &Db, jar: &CookieJar) -> Result<Option<User>> {
+    let Some(cookie) = jar.get(session::SESSION_COOKIE) else {
+        return Ok(None);
+    };
+    db.get_session_user(&hash_token(cookie.value()))
+        .await
+        .wrap_err("Failed to check session")
+}
+
+/// The user a trusted proxy says made a request, created as a member if
+/// they don't exist yet
+async fn proxy_user(
+    app_state: &AppState,
+    peer: Option<IpAddr>,
+    headers: &HeaderMap,
+) -> Result<Option<User>> {
+    let (Some(proxy), Some(peer)) = (&app_state.auth.proxy, peer) else {
+        return Ok(None);
+    };
+    let Some(username) = proxy.username(peer, headers) else {
+        return Ok(None);
+    };
+
+    let user = app_state
+        .db
+        .get_or_create_user(username, password::NO_PASSWORD, Role::Member)
+        .await
+        .wrap_err("Failed to get user from trusted proxy")?;
+    Ok(Some(user))
+}
+
+/// Middleware that only lets logged in requests through, making their
+/// [`User`] available to handlers as an extension. Users can log in through
+/// chordle or a trusted proxy. API requests can instead use an API token,
+/// which is made available as an [`ApiToken`](crate::db::ApiToken). Until
+/// the first user is created chordle stays open to everyone
+pub async fn require_login(
+    State(app_state): State<AppState>,
+    jar: CookieJar,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let is_api = request.uri().path().starts_with("/api/");
+    let error = |err| {
+        if is_api {
+            ApiErrorResponse::from(err).into_response()
+        } else {
+            ErrorResponse::from(err).into_response()
+        }
+    };
+
+    if is_api && let Some(token_hash) = api_token::bearer_token(&request).map(hash_token) {
+        return match app_state.db.use_api_token(&token_hash).await {
+            Ok(Some(token)) => {
+                request.extensions_mut().insert(token);
+                request.extensions_mut().insert(LoginMethod::ApiToken);
+                next.run(request).await
+            }
+            Ok(None) => {
+                ApiErrorResponse::unauthorized("Unknown or revoked API token").into_response()
+            }
+            Err(err) => error(err),
+        };
+    }
+
+    let peer = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(peer)| peer.ip());
+    match proxy_user(&app_state, peer, request.headers()).await {
+        Ok(Some(user)) => {
+            request.extensions_mut().insert(user);
+            request.extensions_mut().insert(LoginMethod::Proxy);
+            return next.run(request).await;
+        }
+        Ok(None) => {}
+        Err(err) => return error(err),
+    }
+
+    match session_user(&app_state.db, &jar).await {
+        Ok(Some(user)) => {
+            request.extensions_mut().insert(user);
+            request.extensions_mut().insert(LoginMethod::Session);
+            return next.run(request).await;
+        }
+        Ok(None) => {}
+        Err(err) => return error(err),
+    }
+
+    if is_public(request.uri().path()) {
+        return next.run(request).await;
+    }
+    match app_state.db.count_users().await {
+        Ok(0) => return next.run(request).await,
+        Ok(_) => {}
+        Err(err) => return error(err),
+    }
+
+    if is_api {
+        ApiErrorResponse::unauthorized("Log in to use the API").into_response()
+    } else if request.method() == Method::GET {
+        let next = request
+            .uri()
+            .path_and_query()
+            .map_or("/", |path| path.as_str());
+        Redirect::to(&ui::login_uri(next)).into_response()
+    } else {
+        Redirect::to(ui::LOGIN_URI).into_response()
+    }
+}
+
+/// The role of whoever made a request. Until the first user is created
+/// everyone is an admin
+impl<S: Send + Sync> FromRequestParts<S> for Role {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(parts
+            .extensions
+            .get::<User>()
+            .map_or(Role::Admin, |user| user.role))
+    }
+}
+
+/// Middleware for UI routers that need at least the `required` role,
+/// layered on with `from_fn_with_state(Role::Member, require_role)`
+pub async fn require_role(
+    State(required): State<Role>,
+    role: Role,
+    request: Request,
+    next: Next,
+) -> Response {
+    if role >= required {
+        next.run(request).await
+    } else {
+        ui::forbidden_page().into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokens_are_random_and_hashed_to_hex() {
+        let token = new_token();
+        assert_ne!(token, new_token());
+        assert_eq!(URL_SAFE_NO_PAD.decode(&token).unwrap().len(), 32);
+
+        let hash = hash_token(&token);
+        assert_eq!(hash, hash_token(&token));
+        assert_eq!(hash.len(), 64);
+        assert!(hash.chars().all(|c| c.is_ascii_hexdigit()));
+    }
+}
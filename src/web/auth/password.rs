@@ -0,0 +1,88 @@
+use std::{io::IsTerminal, sync::LazyLock};
+
+use argon2::{
+    Argon2, PasswordHasher, PasswordVerifier,
+    password_hash::{PasswordHash, SaltString, rand_core::OsRng},
+};
+use color_eyre::{
+    Result,
+    eyre::{WrapErr, eyre},
+};
+
+/// The shortest password that can be set from the command line
+pub const MIN_PASSWORD_LEN: usize = 8;
+
+/// Checked against when nobody has the username being logged in as, so that
+/// logging in takes as long whether or not the user exists
+static DUMMY_HASH: LazyLock<String> =
+    LazyLock::new(|| hash_password("not a real password").expect("Can hash dummy password"));
+
+/// Stored for users created by a trusted proxy, who have no password and
+/// can't log in with one
+pub const NO_PASSWORD: &str = "!";
+
+/// Hash a password with Argon2 and a random salt, as a PHC string
+pub fn hash_password(password: &str) -> Result<String> {
+    let salt = SaltString::generate(&mut OsRng);
+    let hash = Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map_err(|e| eyre!("{e}"))
+        .wrap_err("Failed to hash password")?;
+    Ok(hash.to_string())
+}
+
+/// Check a password against a user's hash. Without a user the password is
+/// still checked against a dummy hash, but never accepted
+pub fn verify_password(password: &str, hash: Option<&str>) -> bool {
+    let hash = hash.filter(|hash| *hash != NO_PASSWORD);
+    let Ok(parsed) = PasswordHash::new(hash.unwrap_or(&DUMMY_HASH)) else {
+        tracing::warn!("Stored password hash can't be parsed");
+        return false;
+    };
+    let verified = Argon2::default()
+        .verify_password(password.as_bytes(), &parsed)
+        .is_ok();
+    verified && hash.is_some()
+}
+
+/// Ask for a new password on the terminal, twice, or read it from the first
+/// line of stdin when it isn't a terminal
+pub fn read_new_password() -> Result<String> {
+    let stdin = std::io::stdin();
+    let password = if stdin.is_terminal() {
+        let password =
+            rpassword::prompt_password("Password: ").wrap_err("Failed to read password")?;
+        let confirmation =
+            rpassword::prompt_password("Confirm password: ").wrap_err("Failed to read password")?;
+        if password != confirmation {
+            return Err(eyre!("Passwords don't match"));
+        }
+        password
+    } else {
+        let mut password = String::new();
+        stdin
+            .read_line(&mut password)
+            .wrap_err("Failed to read password from stdin")?;
+        password.trim_end_matches(['\r', '\n']).to_string()
+    };
+
+    if password.chars().count() < MIN_PASSWORD_LEN {
+        return Err(eyre!(
+            "Passwords must be at least {MIN_PASSWORD_LEN} characters long"
+        ));
+    }
+    Ok(password)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_the_right_password_verifies() {
+        let hash = hash_password("correct horse").unwrap();
+        assert!(verify_password("correct horse", Some(&hash)));
+        assert!(!verify_password("battery staple", Some(&hash)));
+        assert!(!verify_password("not a real password", None));
+    }
+}
@@ -0,0 +1,62 @@
+use std::net::IpAddr;
+
+use axum::http::{HeaderMap, HeaderName};
+use ipnet::IpNet;
+
+use crate::web::validation;
+
+/// A reverse proxy that logs people in itself and passes on who they are in
+/// a header, such as `Remote-User`
+#[derive(Clone, Debug)]
+pub struct TrustedProxy {
+    pub header: HeaderName,
+    /// Where the proxy connects from. The header is ignored from anywhere
+    /// else, since anyone could send it
+    pub proxies: Vec<IpNet>,
+}
+
+impl TrustedProxy {
+    /// The username the proxy vouches for, if the request came from it
+    pub fn username<'a>(&self, peer: IpAddr, headers: &'a HeaderMap) -> Option<&'a str> {
+        let peer = peer.to_canonical();
+        if !self.proxies.iter().any(|proxy| proxy.contains(&peer)) {
+            return None;
+        }
+
+        let username = headers.get(&self.header)?.to_str().ok()?.trim();
+        if validation::is_valid_username(username) {
+            Some(username)
+        } else {
+            tracing::warn!("Ignoring invalid username from trusted proxy");
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn usernames_are_only_trusted_from_proxies() {
+        let proxy = TrustedProxy {
+            header: HeaderName::from_static("remote-user"),
+            proxies: vec!["10.0.0.0/8".parse().unwrap(), "::1/128".parse().unwrap()],
+        };
+        let mut headers = HeaderMap::new();
+        headers.insert("remote-user", " alice ".parse().unwrap());
+
+        let ip = |ip: &str| ip.parse::<IpAddr>().unwrap();
+        assert_eq!(proxy.username(ip("10.1.2.3"), &headers), Some("alice"));
+        assert_eq!(
+            proxy.username(ip("::ffff:10.1.2.3"), &headers),
+            Some("alice")
+        );
+        assert_eq!(proxy.username(ip("::1"), &headers), Some("alice"));
+        assert_eq!(proxy.username(ip("192.168.1.2"), &headers), None);
+        assert_eq!(proxy.username(ip("10.1.2.3"), &HeaderMap::new()), None);
+
+        headers.insert("remote-user", "".parse().unwrap());
+        assert_eq!(proxy.username(ip("10.1.2.3"), &headers), None);
+    }
+}
@@ -0,0 +1,27 @@
+use axum_extra::extract::cookie::{Cookie, SameSite};
+use jiff::{SignedDuration, Timestamp};
+
+/// The cookie holding a logged in browser's session token
+pub const SESSION_COOKIE: &str = "session";
+
+/// How long a login lasts
+pub const SESSION_LENGTH: SignedDuration = SignedDuration::from_hours(30 * 24);
+
+/// When a session started now will expire
+pub fn expires_at() -> Timestamp {
+    Timestamp::now()
+        .checked_add(SESSION_LENGTH)
+        .unwrap_or(Timestamp::MAX)
+}
+
+/// The cookie that logs a browser in. `secure` should only be off when
+/// chordle isn't served over HTTPS
+pub fn session_cookie(token: String, secure: bool) -> Cookie<'static> {
+    Cookie::build((SESSION_COOKIE, token))
+        .path("/")
+        .http_only(true)
+        .secure(secure)
+        .same_site(SameSite::Lax)
+        .max_age(time::Duration::seconds(SESSION_LENGTH.as_secs()))
+        .build()
+}
@@ -0,0 +1,84 @@
+//! Things that can be done from the command line instead of running the
+//! web server
+
+use color_eyre::{
+    Result,
+    eyre::{WrapErr, eyre},
+};
+use jiff::tz::TimeZone;
+
+use crate::db::{Db, Role, Scope};
+
+use super::{auth, validation};
+
+/// Create a user who can log in, asking for their password
+pub async fn create_user(db: &Db, username: &str, role: Role) -> Result<()> {
+    if !validation::is_valid_username(username) {
+        return Err(eyre!(
+            "Usernames must be between 1 and {max} bytes long",
+            max = validation::MAX_USERNAME_LEN
+        ));
+    }
+    let password = auth::password::read_new_password()?;
+    let hash = auth::password::hash_password(&password)?;
+    db.create_user(username, &hash, role)
+        .await
+        .wrap_err_with(|| format!("Failed to create user {username}, do they already exist?"))?;
+    tracing::info!("Created {role} {username}", role = role.as_str());
+
+    Ok(())
+}
+
+/// Create an API token and print it. It can't be shown again
+pub async fn create_api_token(db: &Db, name: &str, scopes: &[Scope]) -> Result<()> {
+    if !validation::is_valid_token_name(name) {
+        return Err(eyre!(
+            "Token names must be between 1 and {max} bytes long",
+            max = validation::MAX_TOKEN_NAME_LEN
+        ));
+    }
+    let token = auth::new_token();
+    let id = db
+        .create_api_token(name, &auth::hash_token(&token), scopes)
+        .await?;
+    tracing::info!("Created API token {id}, it won't be shown again");
+    println!("{token}");
+
+    Ok(())
+}
+
+/// Print every API token, without their secrets
+pub async fn list_api_tokens(db: &Db) -> Result<()> {
+    let date = |at: jiff::Timestamp| at.to_zoned(TimeZone::system()).date().to_string();
+    for token in db.get_api_tokens().await? {
+        let scopes = token
+            .scopes
+            .iter()
+            .map(Scope::as_str)
+            .collect::<Vec<_>>()
+            .join(" ");
+        let status = match (token.revoked_at, token.last_used_at) {
+            (Some(revoked_at), _) => format!("revoked {}", date(revoked_at)),
+            (None, Some(last_used_at)) => format!("last used {}", date(last_used_at)),
+            (None, None) => "never used".to_string(),
+        };
+        println!(
+            "{id}\t{name}\t{scopes}\tcreated {created}\t{status}",
+            id = token.id,
+            name = token.name,
+            created = date(token.created_at),
+        );
+    }
+
+    Ok(())
+}
+
+/// Stop an API token from working
+pub async fn revoke_api_token(db: &Db, id: i64) -> Result<()> {
+    if !db.revoke_api_token(id).await? {
+        return Err(eyre!("There is no unrevoked API token with ID {id}"));
+    }
+    tracing::info!("Revoked API token {id}");
+
+    Ok(())
+}
@@ -1,15 +1,25 @@
-use std::sync::{Arc, RwLock};
+use std::{
+    net::SocketAddr,
+    sync::{Arc, RwLock},
+};
 
-use axum::Router;
+use axum::{Router, middleware};
 use color_eyre::Result;
-use jiff::Timestamp;
-use tokio::net::TcpListener;
+use color_eyre::eyre::Context;
+use jiff::{Span, Timestamp, Zoned};
+use tokio::{net::TcpListener, sync::broadcast};
 use ui::{cache::Cache, l10n::L10N};
+use updates::Update;
 
 use crate::{cli::Cli, db::Db};
+use auth::AuthConfig;
 
 mod api;
+mod auth;
+pub mod commands;
 mod ui;
+mod updates;
+mod validation;
 
 #[derive(Clone, Debug)]
 pub struct AppState {
@@ -17,24 +27,88 @@ pub struct AppState {
     pub db: Arc<Db>,
     pub cache: Arc<RwLock<Cache>>,
     pub l10n: Arc<L10N>,
+    pub updates: broadcast::Sender<Update>,
+    pub auth: Arc<AuthConfig>,
+}
+
+impl AppState {
+    /// Let open pages know that chores changed. Nobody listening is fine
+    pub fn notify(&self, update: Update) {
+        let _ = self.updates.send(update);
+    }
 }
 
 pub async fn run(cli: Cli, db: Db) -> Result<()> {
+    let db = Arc::new(db);
+    if !cli.trash_retention.is_zero() {
+        tokio::spawn(purge_trash(db.clone(), cli.trash_retention));
+    }
+
     let state = AppState {
         launch_time: Arc::new(Timestamp::now()),
-        db: Arc::new(db),
+        db,
         cache: Arc::new(RwLock::new(Cache::new())),
         l10n: Arc::new(L10N::new()),
+        updates: updates::channel(),
+        auth: Arc::new(AuthConfig {
+            secure_cookies: !cli.insecure_cookies,
+            proxy: cli
+                .trusted_proxy_header
+                .map(|header| auth::proxy::TrustedProxy {
+                    header,
+                    proxies: cli.trusted_proxies,
+                }),
+        }),
     };
 
+    if state.db.count_users().await? == 0 {
+        tracing::warn!(
+            "No users have been created, so chordle is open to everyone. Run `chordle create-admin <USERNAME>` to require logging in"
+        );
+    }
+
     let app = Router::new()
         .merge(ui::routes())
         .nest("/api", api::routes())
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            auth::csrf::require_csrf_token,
+        ))
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            auth::require_login,
+        ))
         .with_state(state);
 
     tracing::info!("Starting chordle web server on {}", cli.bind);
     let listener = TcpListener::bind(cli.bind).await?;
-    axum::serve(listener, app).await?;
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .await?;
 
     Ok(())
 }
+
+/// Permanently delete chores that have been in the trash for longer than
+/// `retention`, checking once an hour
+async fn purge_trash(db: Arc<Db>, retention: Span) {
+    let mut interval = tokio::time::interval(std::time::Duration::from_secs(60 * 60));
+    loop {
+        interval.tick().await;
+        let result = Zoned::now()
+            .checked_sub(retention)
+            .wrap_err("Failed to work out the trash cutoff")
+            .map(|cutoff| cutoff.timestamp());
+        let result = match result {
+            Ok(cutoff) => db.purge_chores_deleted_before(cutoff).await,
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(0) => {}
+            Ok(purged) => tracing::info!("Purged {purged} chores from the trash"),
+            Err(e) => tracing::warn!("Failed to purge the trash: {e:#?}"),
+        }
+    }
+}
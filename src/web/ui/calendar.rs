@@ -0,0 +1,300 @@
+use axum::{
+    extract::{Query, State, rejection::QueryRejection},
+    http::HeaderMap,
+};
+use axum_extra::extract::CookieJar;
+use color_eyre::eyre::WrapErr;
+use jiff::{
+    Span, Timestamp, Zoned,
+    civil::{Date, Weekday},
+    tz::TimeZone,
+};
+use maud::{Markup, html};
+use serde::Deserialize;
+
+use crate::{
+    db::{Chore, ChoreEvent, Event},
+    web::{
+        AppState,
+        ui::{
+            CALENDAR_URI, HOME_URI,
+            chore::chore_uri,
+            error::ErrorResponse,
+            l10n::{L10N, Lang},
+            template,
+        },
+    },
+};
+
+/// How many due dates are projected per chore at most, so that chores with
+/// very short intervals don't flood the calendar
+const MAX_PROJECTED: usize = 62;
+
+/// Whether the calendar shows a whole month or a single week
+#[derive(Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum CalendarView {
+    #[default]
+    Month,
+    Week,
+}
+
+impl CalendarView {
+    const ALL: [CalendarView; 2] = [CalendarView::Month, CalendarView::Week];
+
+    fn as_str(self) -> &'static str {
+        match self {
+            CalendarView::Month => "month",
+            CalendarView::Week => "week",
+        }
+    }
+
+    /// The first and last day shown when looking at `date`. Weeks start on
+    /// Monday, and the month view is padded out to whole weeks
+    fn range(self, date: Date) -> (Date, Date) {
+        let (first, last) = match self {
+            CalendarView::Month => (date.first_of_month(), date.last_of_month()),
+            CalendarView::Week => (date, date),
+        };
+        let start = first
+            .checked_sub(Span::new().days(first.weekday().to_monday_zero_offset()))
+            .unwrap_or(first);
+        let end = last
+            .checked_add(Span::new().days(6 - last.weekday().to_monday_zero_offset()))
+            .unwrap_or(last);
+        (start, end)
+    }
+
+    /// The date to look at for the previous or next page of the calendar
+    fn step(self, date: Date, forward: bool) -> Date {
+        let span = match self {
+            CalendarView::Month => Span::new().months(1),
+            CalendarView::Week => Span::new().weeks(1),
+        };
+        let date = match self {
+            CalendarView::Month => date.first_of_month(),
+            CalendarView::Week => date,
+        };
+        let stepped = if forward {
+            date.checked_add(span)
+        } else {
+            date.checked_sub(span)
+        };
+        stepped.unwrap_or(date)
+    }
+}
+
+#[derive(Deserialize, Default)]
+pub struct CalendarQuery {
+    #[serde(default)]
+    view: CalendarView,
+    date: Option<Date>,
+}
+
+fn calendar_uri(view: CalendarView, date: Date) -> String {
+    format!(
+        "{CALENDAR_URI}?view={view}&date={date}",
+        view = view.as_str()
+    )
+}
+
+fn weekday_name(weekday: Weekday, lang: Lang, l10n: &L10N) -> String {
+    l10n.translate(
+        lang,
+        format!("weekday-{n}", n = weekday.to_monday_one_offset()),
+    )
+}
+
+fn month_name(date: Date, lang: Lang, l10n: &L10N) -> String {
+    l10n.translate(lang, format!("month-{n}", n = date.month()))
+}
+
+/// What happens on a single day of the calendar
+struct Day<'a> {
+    date: Date,
+    completed: Vec<(&'a Chore, &'a Event)>,
+    due: Vec<&'a ChoreEvent>,
+}
+
+fn render_day(day: &Day, view: CalendarView, shown: Date, today: Date) -> Markup {
+    html! {
+        td.today[day.date == today]
+            .past[day.date < today]
+            .other-month[view == CalendarView::Month && day.date.month() != shown.month()]
+        {
+            span.day-number { (day.date.day()) }
+            ul {
+                @for (chore, event) in &day.completed {
+                    li.completed title=(event.timestamp.with_time_zone(TimeZone::system()).strftime("%H:%M")) {
+                        a href=(chore_uri(chore.id)) { "✓ " (chore.name) }
+                    }
+                }
+                @for chore_event in &day.due {
+                    li.due {
+                        a href=(chore_uri(chore_event.id)) { (chore_event.name) }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// GET handler for the calendar of past completions and upcoming due dates
+pub async fn calendar_page(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    query: Result<Query<CalendarQuery>, QueryRejection>,
+) -> Result<Markup, ErrorResponse> {
+    // a malformed query shows this month rather than failing the page
+    let query = query.map(|Query(query)| query).unwrap_or_default();
+    let view = query.view;
+
+    let accept_language = headers
+        .get("accept-language")
+        .and_then(|value| value.to_str().ok());
+    let lang = Lang::from_accept_language_header_and_cookie(accept_language, &jar);
+    let l10n = &app_state.l10n;
+
+    let now = Zoned::now().with_time_zone(TimeZone::system());
+    let today = now.date();
+    let shown = query.date.unwrap_or(today);
+    let (start, end) = view.range(shown);
+
+    let to_timestamp = |date: Date| -> Result<Timestamp, ErrorResponse> {
+        Ok(date
+            .to_zoned(TimeZone::system())
+            .wrap_err_with(|| format!("Failed to get the start of {date}"))?
+            .timestamp())
+    };
+    let since = to_timestamp(start)?;
+    let until = to_timestamp(end.tomorrow().unwrap_or(end))?;
+
+    let chores = app_state
+        .db
+        .get_all_chores()
+        .await
+        .wrap_err("Failed to get chores for calendar")?;
+    let completions = app_state
+        .db
+        .get_completions_between(since, until)
+        .await
+        .wrap_err("Failed to get completions for calendar")?;
+    let chore_events = app_state
+        .db
+        .get_all_chore_events()
+        .await
+        .wrap_err("Failed to get due dates for calendar")?;
+
+    let mut days: Vec<Day> = std::iter::successors(Some(start), |date| date.tomorrow().ok())
+        .take_while(|date| *date <= end)
+        .map(|date| Day {
+            date,
+            completed: vec![],
+            due: vec![],
+        })
+        .collect();
+    let day_index = |date: Date| usize::try_from((date - start).get_days()).ok();
+
+    for event in &completions {
+        let Some(chore) = chores.iter().find(|chore| chore.id == event.chore_id) else {
+            continue;
+        };
+        let date = event.timestamp.with_time_zone(TimeZone::system()).date();
+        if let Some(day) = day_index(date).and_then(|index| days.get_mut(index)) {
+            day.completed.push((chore, event));
+        }
+    }
+    for chore_event in &chore_events {
+        let mut dates: Vec<Date> = chore_event
+            .projected_due(&now, end, MAX_PROJECTED)
+            .iter()
+            .map(|due| due.with_time_zone(TimeZone::system()).date())
+            .filter(|date| *date >= start)
+            .collect();
+        dates.dedup();
+        for date in dates {
+            if let Some(day) = day_index(date).and_then(|index| days.get_mut(index)) {
+                day.due.push(chore_event);
+            }
+        }
+    }
+
+    let title = match view {
+        CalendarView::Month => format!(
+            "{month} {year}",
+            month = month_name(shown, lang, l10n),
+            year = shown.year()
+        ),
+        CalendarView::Week => format!(
+            "{day} {month} {year}",
+            day = start.day(),
+            month = month_name(start, lang, l10n),
+            year = start.year()
+        ),
+    };
+
+    Ok(template::page(
+        lang,
+        &l10n.translate(lang, "calendar"),
+        html! {
+            main.calendar {
+                h1 { (title) }
+                nav.calendar-nav {
+                    a href=(calendar_uri(view, view.step(shown, false))) { "← " (l10n.translate(lang, "previous")) }
+                    a href=(calendar_uri(view, today)) { (l10n.translate(lang, "today")) }
+                    a href=(calendar_uri(view, view.step(shown, true))) { (l10n.translate(lang, "next")) " →" }
+                    span.calendar-views {
+                        @for option in CalendarView::ALL {
+                            @if option == view {
+                                strong { (l10n.translate(lang, format!("calendar-{}", option.as_str()))) }
+                            } @else {
+                                a href=(calendar_uri(option, shown)) {
+                                    (l10n.translate(lang, format!("calendar-{}", option.as_str())))
+                                }
+                            }
+                        }
+                    }
+                }
+                table.calendar-grid .week[view == CalendarView::Week] {
+                    thead {
+                        tr {
+                            @for day in days.iter().take(7) {
+                                th { (weekday_name(day.date.weekday(), lang, l10n)) }
+                            }
+                        }
+                    }
+                    tbody {
+                        @for week in days.chunks(7) {
+                            tr {
+                                @for day in week {
+                                    (render_day(day, view, shown, today))
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            footer {
+                { a href=(HOME_URI) { (l10n.translate(lang, "back-to-chores")) } }
+            }
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use jiff::civil::date;
+
+    use super::*;
+
+    #[test]
+    fn month_view_is_padded_to_whole_weeks() {
+        // October 2026 starts on a Thursday and ends on a Saturday
+        let (start, end) = CalendarView::Month.range(date(2026, 10, 18));
+        assert_eq!((start, end), (date(2026, 9, 28), date(2026, 11, 1)));
+
+        let (start, end) = CalendarView::Week.range(date(2026, 10, 18));
+        assert_eq!((start, end), (date(2026, 10, 12), date(2026, 10, 18)));
+    }
+}
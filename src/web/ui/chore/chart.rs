@@ -0,0 +1,115 @@
+use jiff::{Timestamp, tz::TimeZone};
+use maud::{Markup, html};
+
+/// The chart's size in SVG user units; it is scaled to fit the page
+const WIDTH: f64 = 600.0;
+const HEIGHT: f64 = 200.0;
+/// Room around the plot for axis labels
+const MARGIN_LEFT: f64 = 40.0;
+const MARGIN: f64 = 16.0;
+
+/// One completion's bar, in SVG user units
+#[derive(Debug)]
+struct Bar {
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+    late: bool,
+}
+
+/// Where the bars go, along with the value range of the y axis
+fn layout(points: &[(Timestamp, f64)]) -> (Vec<Bar>, f64, f64) {
+    let lowest = points
+        .iter()
+        .map(|(_, delta)| *delta)
+        .fold(-1.0, f64::min)
+        .floor();
+    let highest = points
+        .iter()
+        .map(|(_, delta)| *delta)
+        .fold(1.0, f64::max)
+        .ceil();
+    let y = |delta: f64| MARGIN + (highest - delta) / (highest - lowest) * (HEIGHT - 2.0 * MARGIN);
+
+    let first = points.first().map_or(0, |(at, _)| at.as_millisecond());
+    let last = points.last().map_or(0, |(at, _)| at.as_millisecond());
+    let plot_width = WIDTH - MARGIN_LEFT - MARGIN;
+    let x = |at: &Timestamp| {
+        if last == first {
+            MARGIN_LEFT + plot_width / 2.0
+        } else {
+            MARGIN_LEFT + (at.as_millisecond() - first) as f64 / (last - first) as f64 * plot_width
+        }
+    };
+    let width = (plot_width / points.len().max(1) as f64 * 0.6).clamp(2.0, 16.0);
+
+    let bars = points
+        .iter()
+        .map(|(at, delta)| {
+            let top = y(delta.max(0.0));
+            let bottom = y(delta.min(0.0));
+            Bar {
+                x: x(at) - width / 2.0,
+                y: top,
+                width,
+                height: (bottom - top).max(1.0),
+                late: *delta >= 1.0,
+            }
+        })
+        .collect();
+    (bars, lowest, highest)
+}
+
+/// A bar chart of how many days late each completion was, with late
+/// completions above the zero line and early ones below it
+pub fn render_delta_chart(points: &[(Timestamp, f64)], title: &str) -> Markup {
+    let (bars, lowest, highest) = layout(points);
+    let zero = MARGIN + highest / (highest - lowest) * (HEIGHT - 2.0 * MARGIN);
+    let date = |at: &Timestamp| at.to_zoned(TimeZone::system()).date().to_string();
+
+    html! {
+        svg.delta-chart xmlns="http://www.w3.org/2000/svg" viewBox=(format!("0 0 {WIDTH} {HEIGHT}")) role="img" aria-label=(title) {
+            title { (title) }
+            line.axis x1=(MARGIN_LEFT) y1=(zero) x2=(WIDTH - MARGIN) y2=(zero) {}
+            text.label x=(MARGIN_LEFT - 4.0) y=(MARGIN) text-anchor="end" dominant-baseline="middle" { (format!("+{highest}")) }
+            text.label x=(MARGIN_LEFT - 4.0) y=(zero) text-anchor="end" dominant-baseline="middle" { "0" }
+            text.label x=(MARGIN_LEFT - 4.0) y=(HEIGHT - MARGIN) text-anchor="end" dominant-baseline="middle" { (lowest) }
+            @if let (Some((first, _)), Some((last, _))) = (points.first(), points.last()) {
+                text.label x=(MARGIN_LEFT) y=(HEIGHT - 2.0) { (date(first)) }
+                text.label x=(WIDTH - MARGIN) y=(HEIGHT - 2.0) text-anchor="end" { (date(last)) }
+            }
+            @for (bar, (at, delta)) in bars.iter().zip(points) {
+                rect .late[bar.late] .on-time[!bar.late] x=(bar.x) y=(bar.y) width=(bar.width) height=(bar.height) {
+                    title { (format!("{date}: {delta:+.1}", date = date(at))) }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn late_bars_sit_above_the_zero_line() {
+        let day = 24 * 60 * 60;
+        let points = [
+            (Timestamp::from_second(0).unwrap(), 3.0),
+            (Timestamp::from_second(day).unwrap(), -2.0),
+            (Timestamp::from_second(2 * day).unwrap(), 0.5),
+        ];
+        let (bars, lowest, highest) = layout(&points);
+        assert_eq!((lowest, highest), (-2.0, 3.0));
+
+        let zero = MARGIN + highest / (highest - lowest) * (HEIGHT - 2.0 * MARGIN);
+        assert!(bars[0].late && (bars[0].y + bars[0].height - zero).abs() < 1e-9);
+        assert!(!bars[1].late && (bars[1].y - zero).abs() < 1e-9);
+        assert!(!bars[2].late);
+        for bar in &bars {
+            assert!(bar.x >= MARGIN_LEFT - bar.width && bar.x + bar.width <= WIDTH);
+            assert!(bar.y >= MARGIN && bar.y + bar.height <= HEIGHT - MARGIN + 1e-9);
+        }
+    }
+}
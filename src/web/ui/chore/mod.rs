@@ -0,0 +1,260 @@
+use axum::{
+    extract::{Path, State},
+    http::HeaderMap,
+    response::{IntoResponse, Redirect, Response},
+};
+use axum_extra::extract::CookieJar;
+use color_eyre::eyre::WrapErr;
+use fluent::fluent_args;
+use jiff::{Timestamp, tz::TimeZone};
+use maud::{Markup, html};
+
+use crate::{
+    db::{Chore, ChoreEvent, ChoreId, Event, Member, MemberId, Role, User},
+    stats::{ChoreStats, completion_delta},
+    web::{
+        AppState,
+        ui::{
+            HOME_URI, STATS_URI,
+            error::ErrorResponse,
+            l10n::{L10N, Lang},
+            manager::history_uri,
+            template,
+        },
+    },
+};
+
+mod chart;
+
+/// The URI of the detail page for a chore
+pub fn chore_uri(chore_id: ChoreId) -> String {
+    format!("/chore/{chore_id}")
+}
+
+fn member_name(member_id: Option<MemberId>, members: &[Member], lang: Lang, l10n: &L10N) -> String {
+    match member_id {
+        Some(member_id) => members
+            .iter()
+            .find(|member| member.id == member_id)
+            .map_or_else(|| member_id.to_string(), |member| member.name.clone()),
+        None => l10n.translate(lang, "nobody"),
+    }
+}
+
+fn render_details(
+    chore: &Chore,
+    chore_event: Option<&ChoreEvent>,
+    lang: Lang,
+    l10n: &L10N,
+) -> Markup {
+    let date = |timestamp: &jiff::Zoned| {
+        timestamp
+            .with_time_zone(TimeZone::system())
+            .date()
+            .to_string()
+    };
+    let schedule = chore.schedule.to_string();
+
+    html! {
+        dl.chore-details {
+            dt { (l10n.translate(lang, "interval")) }
+            dd { (format!("{interval:#}", interval = chore.interval)) }
+            @if !schedule.is_empty() {
+                dt { (l10n.translate(lang, "schedule")) }
+                dd { code { (schedule) } }
+            }
+            dt { (l10n.translate(lang, "effort")) }
+            dd { (chore.effort) }
+            @if let Some(room) = &chore.room {
+                dt { (l10n.translate(lang, "room")) }
+                dd { (room) }
+            }
+            dt { (l10n.translate(lang, "status")) }
+            dd { (l10n.translate(lang, format!("status-{status}", status = chore.status.as_str()))) }
+            @if let Some(last_done) = chore_event.and_then(|chore_event| chore_event.timestamp.as_ref()) {
+                dt { (l10n.translate(lang, "last-completed")) }
+                dd { (date(last_done)) }
+            }
+            @if let Some(next_due) = chore_event.and_then(|chore_event| chore_event.next_due()) {
+                dt { (l10n.translate(lang, "next-due")) }
+                dd { (date(&next_due)) }
+            }
+        }
+    }
+}
+
+fn render_stats(stats: &ChoreStats, members: &[Member], lang: Lang, l10n: &L10N) -> Markup {
+    html! {
+        table {
+            tbody {
+                tr { th { (l10n.translate(lang, "times-completed")) } td { (stats.num_completed) } }
+                tr { th { (l10n.translate(lang, "times-overdue")) } td { (stats.num_overdue) } }
+                tr {
+                    th { (l10n.translate(lang, "mean-days-overdue")) }
+                    td { (format!("{mean:.1} ± {sd:.2}", mean = stats.mean_overdue_days, sd = stats.variance_overdue_days.sqrt())) }
+                }
+                tr {
+                    th { (l10n.translate(lang, "median-days-overdue")) }
+                    td { (format!("{median:.1}", median = stats.median_overdue_days)) }
+                }
+                tr { th { (l10n.translate(lang, "times-snoozed")) } td { (stats.num_snoozed) } }
+                tr { th { (l10n.translate(lang, "times-skipped")) } td { (stats.num_skipped) } }
+            }
+        }
+        @if !members.is_empty() && !stats.by_member.is_empty() {
+            table {
+                thead {
+                    tr {
+                        th { (l10n.translate(lang, "member")) }
+                        th { (l10n.translate(lang, "times-completed")) }
+                        th { (l10n.translate(lang, "times-overdue")) }
+                        th { (l10n.translate(lang, "mean-days-overdue")) }
+                    }
+                }
+                tbody {
+                    @for stat in &stats.by_member {
+                        tr {
+                            td { (member_name(stat.member_id, members, lang, l10n)) }
+                            td { (stat.num_completed) }
+                            td { (stat.num_overdue) }
+                            td { (format!("{mean:.1}", mean = stat.mean_overdue_days)) }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn render_timeline(
+    deltas: &[(&Event, Option<f64>)],
+    members: &[Member],
+    users: &[User],
+    lang: Lang,
+    l10n: &L10N,
+) -> Markup {
+    html! {
+        @if deltas.is_empty() {
+            p { (l10n.translate(lang, "no-completions")) }
+        }
+        ol.timeline reversed {
+            @for (event, delta) in deltas.iter().rev() {
+                li {
+                    time datetime=(event.timestamp.timestamp()) {
+                        (event.timestamp.with_time_zone(TimeZone::system()).strftime("%Y-%m-%d %H:%M"))
+                    }
+                    @if !members.is_empty() {
+                        span.completed-by { (member_name(event.member_id, members, lang, l10n)) }
+                    }
+                    @if let Some(user) = event.user_id.and_then(|user_id| users.iter().find(|user| user.id == user_id)) {
+                        span.recorded-by { (l10n.translate_with(lang, "recorded-by", fluent_args!["user" => user.username.as_str()])) }
+                    }
+                    @if let Some(delta) = delta {
+                        @let days = delta.round().abs() as i64;
+                        @if *delta >= 1.0 {
+                            span.delta.late { (l10n.translate_with(lang, "completed-late", fluent_args!["days" => days])) }
+                        } @else if *delta <= -1.0 {
+                            span.delta.early { (l10n.translate_with(lang, "completed-early", fluent_args!["days" => days])) }
+                        } @else {
+                            span.delta { (l10n.translate(lang, "completed-on-time")) }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// GET handler for a chore's detail page
+pub async fn chore_page(
+    headers: HeaderMap,
+    jar: CookieJar,
+    role: Role,
+    State(app_state): State<AppState>,
+    Path(chore_id): Path<i64>,
+) -> Result<Response, ErrorResponse> {
+    let chore_id = ChoreId(chore_id);
+    let accept_language = headers
+        .get("accept-language")
+        .and_then(|value| value.to_str().ok());
+    let lang = Lang::from_accept_language_header_and_cookie(accept_language, &jar);
+    let l10n = &app_state.l10n;
+
+    let chore = app_state
+        .db
+        .get_chore(chore_id)
+        .await
+        .wrap_err_with(|| format!("Failed to get chore {chore_id}"))?;
+    let Some(chore) = chore else {
+        return Ok(Redirect::to(HOME_URI).into_response());
+    };
+    let chore_event = app_state
+        .db
+        .get_chore_event(chore_id)
+        .await
+        .wrap_err_with(|| format!("Failed to get due date of chore {chore_id}"))?;
+    let events = app_state
+        .db
+        .get_chore_completions(chore_id)
+        .await
+        .wrap_err_with(|| format!("Failed to get completions of chore {chore_id}"))?;
+    let pauses = app_state
+        .db
+        .get_pauses()
+        .await
+        .wrap_err("Failed to get vacations")?;
+    let members = app_state
+        .db
+        .get_members()
+        .await
+        .wrap_err("Failed to get members")?;
+    let users = app_state
+        .db
+        .get_users()
+        .await
+        .wrap_err("Failed to get users")?;
+    let stats = crate::stats::get_stats(&app_state.db, chore_id)
+        .await
+        .wrap_err_with(|| format!("Failed to get stats for chore {chore_id}"))?;
+
+    let deltas = completion_delta::calculate_event_delta_days(&chore, &events, &pauses);
+    let points: Vec<(Timestamp, f64)> = deltas
+        .iter()
+        .filter_map(|(event, delta)| delta.map(|delta| (event.timestamp.timestamp(), delta)))
+        .collect();
+
+    Ok(template::page(
+        lang,
+        &chore.name,
+        html! {
+            main.stats.chore-detail {
+                h1 {
+                    @if let Some(icon) = &chore.icon {
+                        (super::home::render_chore_icon(icon))
+                        " "
+                    }
+                    (chore.name)
+                }
+                (render_details(&chore, chore_event.as_ref(), lang, l10n))
+                @if !points.is_empty() {
+                    h2 { (l10n.translate(lang, "days-late-over-time")) }
+                    (chart::render_delta_chart(&points, &l10n.translate(lang, "days-late-over-time")))
+                }
+                @if let Some(stats) = &stats {
+                    h2 { (l10n.translate(lang, "stats")) }
+                    (render_stats(stats, &members, lang, l10n))
+                }
+                h2 { (l10n.translate(lang, "completions")) }
+                (render_timeline(&deltas, &members, &users, lang, l10n))
+            }
+            footer {
+                { a href=(HOME_URI) { (l10n.translate(lang, "back-to-chores")) } }
+                { a href=(STATS_URI) { (l10n.translate(lang, "stats")) } }
+                @if role.can_manage() {
+                    { a href=(history_uri(chore_id)) { (l10n.translate(lang, "edit-history")) } }
+                }
+            }
+        },
+    )
+    .into_response())
+}
@@ -1,39 +1,219 @@
 use crate::{
-    db::ChoreEvent,
+    db::{ChoreEvent, ChoreId, EventKind, Member, MemberId, Role, User, UserId},
     web::{
         AppState,
-        ui::{MANAGER_URI, REDO_URI, STATS_URI, UNDO_URI},
+        auth::csrf::CsrfToken,
+        ui::{CALENDAR_URI, LOGOUT_URI, MANAGER_URI, MEMBER_URI, REDO_URI, STATS_URI, UNDO_URI},
+        updates::Update,
+        validation,
     },
 };
 use axum::{
+    Extension, Form,
     body::Body,
-    extract::{Path, State},
-    http::HeaderMap,
+    extract::{Path, Query, State, rejection::QueryRejection},
+    http::{HeaderMap, Uri, header},
     response::{IntoResponse, Redirect, Response},
 };
-use axum_extra::extract::CookieJar;
-use color_eyre::eyre::Context;
+use axum_extra::extract::{
+    CookieJar,
+    cookie::{Cookie, SameSite},
+};
+use color_eyre::{Result, eyre::Context};
 use fluent::fluent_args;
-use jiff::{Span, SpanTotal, Unit, Zoned};
+use jiff::{Span, SpanTotal, Unit, Zoned, civil::Date, tz::TimeZone};
 use maud::{Markup, PreEscaped, html};
+use serde::Deserialize;
 
 use super::{
     HOME_URI,
+    chore::chore_uri,
     error::ErrorResponse,
     l10n::{L10N, Lang},
 };
 
+/// The cookie holding the member this device records completions for
+const MEMBER_COOKIE: &str = "member";
+
+/// Swaps a pressed chore's contents for a spinner
+const LOADING_SPINNER_JS: &str = include_str!("./static_files/loading-spinner.js");
+
+/// Sent by script pressing buttons in the background, see
+/// `static_files/fragments.js`
+const FRAGMENT_HEADER: &str = "x-fragment";
+
+#[derive(Deserialize)]
+pub struct MemberForm {
+    member: String,
+}
+
+#[derive(Deserialize)]
+pub struct SnoozeForm {
+    until: String,
+}
+
+#[derive(Deserialize, Default)]
+pub struct HomeQuery {
+    /// Only show the chores in this room
+    room: Option<String>,
+    /// Show chores under a heading for each room
+    #[serde(default)]
+    group: bool,
+}
+
+/// Send the browser back to the view of the home page a form was posted
+/// from, so a filtered or grouped view survives pressing a button
+fn back_home(headers: &HeaderMap) -> Redirect {
+    headers
+        .get(header::REFERER)
+        .and_then(|referer| referer.to_str().ok())
+        .and_then(|referer| referer.parse::<Uri>().ok())
+        .filter(|referer| referer.path() == HOME_URI)
+        .and_then(|referer| {
+            referer
+                .path_and_query()
+                .map(|path_and_query| Redirect::to(path_and_query.as_str()))
+        })
+        .unwrap_or_else(|| Redirect::to(HOME_URI))
+}
+
+/// Whether a button was pressed by script that only wants the parts of the
+/// page that changed back, rather than a redirect to the whole page
+fn wants_fragment(headers: &HeaderMap) -> bool {
+    headers.contains_key(FRAGMENT_HEADER)
+}
+
+/// The re-rendered card of a chore along with the undo and redo buttons,
+/// for script to swap into the page in place of the old ones
+async fn render_fragment(
+    app_state: &AppState,
+    chore_id: Option<ChoreId>,
+    role: Role,
+    csrf: &CsrfToken,
+    lang: Lang,
+) -> Result<Markup> {
+    let chore_event = match chore_id {
+        Some(chore_id) => app_state
+            .db
+            .get_chore_event(chore_id)
+            .await
+            .wrap_err_with(|| format!("Failed to get chore {chore_id}"))?,
+        None => None,
+    };
+    let can_undo = app_state
+        .db
+        .can_undo_chore_event()
+        .await
+        .wrap_err("Can check if undo is possible")?;
+    let can_redo = app_state
+        .db
+        .can_redo_chore_event()
+        .await
+        .wrap_err("Can check if redo is possible")?;
+
+    Ok(html! {
+        @if let Some(chore_event) = &chore_event {
+            (render_chore(chore_event, role, csrf, lang, &app_state.l10n))
+        }
+        (render_undo_redo(role, can_undo, can_redo, csrf, lang, &app_state.l10n))
+    })
+}
+
+/// Answer a button press with either the parts of the page that changed or
+/// a redirect back home, depending on who pressed it
+async fn respond_to_press(
+    app_state: &AppState,
+    chore_id: Option<ChoreId>,
+    role: Role,
+    csrf: &CsrfToken,
+    headers: &HeaderMap,
+    jar: &CookieJar,
+) -> Result<Response, ErrorResponse> {
+    if !wants_fragment(headers) {
+        return Ok(back_home(headers).into_response());
+    }
+
+    let accept_language = headers
+        .get("accept-language")
+        .and_then(|value| value.to_str().ok());
+    let lang = Lang::from_accept_language_header_and_cookie(accept_language, jar);
+    let fragment = render_fragment(app_state, chore_id, role, csrf, lang)
+        .await
+        .wrap_err("Failed to render home page fragment")?;
+    Ok(([(header::CACHE_CONTROL, "no-store")], fragment).into_response())
+}
+
+/// The home page showing only the chores in `room`
+fn room_uri(room: &str) -> String {
+    format!("{HOME_URI}?room={room}", room = super::percent_encode(room))
+}
+
+/// Split chores into rooms in alphabetical order, with chores that aren't
+/// in a room last. Chores keep their order within each room
+fn group_by_room(chores: Vec<ChoreEvent>) -> Vec<(Option<String>, Vec<ChoreEvent>)> {
+    let mut groups: Vec<(Option<String>, Vec<ChoreEvent>)> = Vec::new();
+    for chore in chores {
+        match groups.iter_mut().find(|(room, _)| *room == chore.room) {
+            Some((_, group)) => group.push(chore),
+            None => groups.push((chore.room.clone(), vec![chore])),
+        }
+    }
+    groups.sort_by(|(a, _), (b, _)| match (a, b) {
+        (Some(a), Some(b)) => a.cmp(b),
+        (a, b) => b.is_some().cmp(&a.is_some()),
+    });
+    groups
+}
+
+/// The member picked on this device, if any
+fn member_from_cookie(jar: &CookieJar) -> Option<MemberId> {
+    jar.get(MEMBER_COOKIE)
+        .and_then(|cookie| cookie.value().parse::<i64>().ok())
+        .map(Into::into)
+}
+
+/// The member picked on this device, ignoring members that have since been deleted
+pub async fn current_member(app_state: &AppState, jar: &CookieJar) -> Result<Option<MemberId>> {
+    let Some(member_id) = member_from_cookie(jar) else {
+        return Ok(None);
+    };
+    let member = app_state
+        .db
+        .get_member(member_id)
+        .await
+        .wrap_err_with(|| format!("Failed to get member {member_id}"))?;
+    Ok(member.map(|member| member.id))
+}
+
 pub async fn home(
     State(app_state): State<AppState>,
     headers: HeaderMap,
     jar: CookieJar,
+    role: Role,
+    user: Option<Extension<User>>,
+    Extension(csrf): Extension<CsrfToken>,
+    query: Result<Query<HomeQuery>, QueryRejection>,
 ) -> Result<impl IntoResponse, ErrorResponse> {
-    let chore_events = app_state
+    let query = query.map(|Query(query)| query).unwrap_or_default();
+    let mut chore_events = app_state
         .db
         .get_all_chore_events()
         .await
         .wrap_err("Failed to get all chores")?;
+    if let Some(room) = &query.room {
+        chore_events.retain(|chore_event| {
+            chore_event
+                .room
+                .as_ref()
+                .is_some_and(|chore_room| chore_room.eq_ignore_ascii_case(room))
+        });
+    }
     let chore_events = sort_chores(chore_events);
+    let rooms = app_state
+        .db
+        .get_rooms()
+        .await
+        .wrap_err("Failed to get rooms")?;
 
     let can_undo = app_state
         .db
@@ -46,6 +226,21 @@ pub async fn home(
         .await
         .wrap_err("Can check if redo is possible")?;
 
+    let members = app_state
+        .db
+        .get_members()
+        .await
+        .wrap_err("Failed to get members")?;
+    let current_member = member_from_cookie(&jar);
+    let now = Zoned::now();
+    let on_vacation = app_state
+        .db
+        .get_vacations()
+        .await
+        .wrap_err("Failed to get vacations")?
+        .iter()
+        .any(|vacation| vacation.is_active(&now));
+
     let accept_language = headers
         .get("accept-language")
         .and_then(|value| value.to_str().ok());
@@ -56,43 +251,71 @@ pub async fn home(
         "Chordle",
         html! {
             main.home {
-                div.chores {
-                    @for chore_event in chore_events {
-                        (render_chore(&chore_event, lang, &app_state.l10n))
-                    }
+                @if on_vacation {
+                    p.vacation-banner { (app_state.l10n.translate(lang, "on-vacation")) }
                 }
-            }
-            footer {
-                div.undo-redo {
-                    @if can_undo {
-                        form action=(UNDO_URI) method="POST" {
-                            button type="submit" class="undo" {
-                                img src="/icons/undo.svg" alt=(app_state.l10n.translate(lang, "undo"));
+                @if !rooms.is_empty() {
+                    (render_room_nav(&rooms, &query, lang, &app_state.l10n))
+                }
+                @if query.group && query.room.is_none() {
+                    @for (room, chore_events) in group_by_room(chore_events) {
+                        section.room {
+                            h2 {
+                                @match &room {
+                                    Some(room) => a href=(room_uri(room)) { (room) },
+                                    None => (app_state.l10n.translate(lang, "no-room")),
+                                }
+                            }
+                            div.chores {
+                                @for chore_event in chore_events {
+                                    (render_chore(&chore_event, role, &csrf, lang, &app_state.l10n))
+                                }
                             }
                         }
                     }
-                    @if can_redo {
-                        form action=(REDO_URI) method="POST" {
-                            button type="submit" class="redo" {
-                                img src="/icons/redo.svg" alt=(app_state.l10n.translate(lang, "redo"));
-                            }
+                } @else {
+                    div.chores {
+                        @for chore_event in chore_events {
+                            (render_chore(&chore_event, role, &csrf, lang, &app_state.l10n))
                         }
                     }
                 }
+            }
+            footer {
+                (render_undo_redo(role, can_undo, can_redo, &csrf, lang, &app_state.l10n))
+                @if role.can_record() {
+                    @if !members.is_empty() {
+                        (render_member_select(&members, current_member, &csrf, lang, &app_state.l10n))
+                    }
+                }
                 div {
                     a href=(STATS_URI) {
                         (PreEscaped(r#"<svg xmlns="http://www.w3.org/2000/svg" height="1em" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" class="lucide lucide-chart-candlestick-icon lucide-chart-candlestick"><path d="M9 5v4"/><rect width="4" height="6" x="7" y="9" rx="1"/><path d="M9 15v2"/><path d="M17 3v2"/><rect width="4" height="8" x="15" y="5" rx="1"/><path d="M17 13v3"/><path d="M3 3v16a2 2 0 0 0 2 2h16"/></svg>"#))
                         (app_state.l10n.translate(lang, "stats"))
                     }
-                    a href=(MANAGER_URI) {
-                        (PreEscaped(r#"<svg xmlns="http://www.w3.org/2000/svg" height="1em" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" class="lucide lucide-cog-icon lucide-cog"><path d="M12 20a8 8 0 1 0 0-16 8 8 0 0 0 0 16Z"/><path d="M12 14a2 2 0 1 0 0-4 2 2 0 0 0 0 4Z"/><path d="M12 2v2"/><path d="M12 22v-2"/><path d="m17 20.66-1-1.73"/><path d="M11 10.27 7 3.34"/><path d="m20.66 17-1.73-1"/><path d="m3.34 7 1.73 1"/><path d="M14 12h8"/><path d="M2 12h2"/><path d="m20.66 7-1.73 1"/><path d="m3.34 17 1.73-1"/><path d="m17 3.34-1 1.73"/><path d="m11 13.73-4 6.93"/></svg>"#))
-                        (app_state.l10n.translate(lang, "manage-chores"))
+                    a href=(CALENDAR_URI) {
+                        (PreEscaped(r#"<svg xmlns="http://www.w3.org/2000/svg" height="1em" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" class="lucide lucide-calendar-icon lucide-calendar"><path d="M8 2v4"/><path d="M16 2v4"/><rect width="18" height="18" x="3" y="4" rx="2"/><path d="M3 10h18"/></svg>"#))
+                        (app_state.l10n.translate(lang, "calendar"))
+                    }
+                    @if role.can_manage() {
+                        a href=(MANAGER_URI) {
+                            (PreEscaped(r#"<svg xmlns="http://www.w3.org/2000/svg" height="1em" viewBox="0 0 24 24" fill="none" stroke="currentColor" stroke-width="2" stroke-linecap="round" stroke-linejoin="round" class="lucide lucide-cog-icon lucide-cog"><path d="M12 20a8 8 0 1 0 0-16 8 8 0 0 0 0 16Z"/><path d="M12 14a2 2 0 1 0 0-4 2 2 0 0 0 0 4Z"/><path d="M12 2v2"/><path d="M12 22v-2"/><path d="m17 20.66-1-1.73"/><path d="M11 10.27 7 3.34"/><path d="m20.66 17-1.73-1"/><path d="m3.34 7 1.73 1"/><path d="M14 12h8"/><path d="M2 12h2"/><path d="m20.66 7-1.73 1"/><path d="m3.34 17 1.73-1"/><path d="m17 3.34-1 1.73"/><path d="m11 13.73-4 6.93"/></svg>"#))
+                            (app_state.l10n.translate(lang, "manage-chores"))
+                        }
+                    }
+                    @if let Some(Extension(user)) = &user {
+                        form.logout method="post" action=(LOGOUT_URI) {
+                            (csrf)
+                            button type="submit" title=(user.username) { (app_state.l10n.translate(lang, "log-out")) }
+                        }
                     }
                 }
             }
             (PreEscaped(r#"<script>"#));
-            (PreEscaped(include_str!("./static_files/loading-spinner.js")));
-            (PreEscaped(include_str!("./static_files/reload.js")));
+            (PreEscaped(LOADING_SPINNER_JS));
+            (PreEscaped(include_str!("./static_files/live-updates.js")));
+            (PreEscaped(include_str!("./static_files/fragments.js")));
+            (PreEscaped(include_str!("./static_files/offline.js")));
             (PreEscaped(r#"</script>"#));
         },
     );
@@ -106,45 +329,259 @@ pub async fn home(
         .expect("Can build home response"))
 }
 
+/// The ID of whoever is logged in, to attribute what they record to them
+pub(super) fn user_id(user: Option<Extension<User>>) -> Option<UserId> {
+    user.map(|Extension(user)| user.id)
+}
+
 pub async fn record_event(
     State(app_state): State<AppState>,
     Path(chore_id): Path<i64>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    role: Role,
+    user: Option<Extension<User>>,
+    Extension(csrf): Extension<CsrfToken>,
+) -> Result<Response, ErrorResponse> {
+    let member_id = current_member(&app_state, &jar).await?;
+
+    let event = app_state
+        .db
+        .record_chore_event(chore_id.into(), member_id, user_id(user))
+        .await
+        .wrap_err_with(|| format!("Failed to record event for chore with ID: {}", chore_id))?;
+    if event.is_some() {
+        app_state.notify(Update::Chore(chore_id.into()));
+    } else {
+        tracing::warn!("Not recording event for missing or trashed chore {chore_id}");
+    }
+    respond_to_press(
+        &app_state,
+        event.map(|event| event.chore_id),
+        role,
+        &csrf,
+        &headers,
+        &jar,
+    )
+    .await
+}
+
+/// POST handler to put a chore off until the start of a chosen day
+pub async fn snooze_chore(
+    State(app_state): State<AppState>,
+    Path(chore_id): Path<i64>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    user: Option<Extension<User>>,
+    Form(form): Form<SnoozeForm>,
 ) -> Result<Redirect, ErrorResponse> {
+    let until = form
+        .until
+        .parse::<Date>()
+        .ok()
+        .and_then(|until| until.to_zoned(TimeZone::system()).ok());
+    let Some(until) = until else {
+        tracing::warn!(
+            "Ignoring snooze with invalid date: {until}",
+            until = form.until
+        );
+        return Ok(back_home(&headers));
+    };
+
+    let member_id = current_member(&app_state, &jar).await?;
     app_state
         .db
-        .record_chore_event(chore_id.into())
+        .postpone_chore(
+            chore_id.into(),
+            EventKind::Snoozed,
+            until,
+            member_id,
+            user_id(user),
+        )
         .await
-        .wrap_err_with(|| format!("Failed to record event for chore with ID: {}", chore_id))?;
-    Ok(Redirect::to(HOME_URI))
+        .wrap_err_with(|| format!("Failed to snooze chore with ID: {chore_id}"))?;
+    app_state.notify(Update::Chore(chore_id.into()));
+    Ok(back_home(&headers))
 }
 
-pub async fn undo_event(State(app_state): State<AppState>) -> Result<Redirect, ErrorResponse> {
+/// POST handler to skip the current occurrence of a chore
+pub async fn skip_chore(
+    State(app_state): State<AppState>,
+    Path(chore_id): Path<i64>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    user: Option<Extension<User>>,
+) -> Result<Redirect, ErrorResponse> {
+    let chore_event = app_state
+        .db
+        .get_chore_event(chore_id.into())
+        .await
+        .wrap_err_with(|| format!("Failed to get chore with ID: {chore_id}"))?;
+    let Some(chore_event) = chore_event else {
+        return Ok(back_home(&headers));
+    };
+
+    let member_id = current_member(&app_state, &jar).await?;
+    let until = chore_event.next_due_when_skipped(&Zoned::now());
     app_state
+        .db
+        .postpone_chore(
+            chore_id.into(),
+            EventKind::Skipped,
+            until,
+            member_id,
+            user_id(user),
+        )
+        .await
+        .wrap_err_with(|| format!("Failed to skip chore with ID: {chore_id}"))?;
+    app_state.notify(Update::Chore(chore_id.into()));
+    Ok(back_home(&headers))
+}
+
+/// POST handler to pick which member this device records completions for
+pub async fn select_member(
+    headers: HeaderMap,
+    jar: CookieJar,
+    Form(form): Form<MemberForm>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    let jar = match form.member.parse::<i64>() {
+        Ok(member_id) => jar.add(
+            Cookie::build((MEMBER_COOKIE, member_id.to_string()))
+                .path("/")
+                .http_only(true)
+                .same_site(SameSite::Lax)
+                .permanent()
+                .build(),
+        ),
+        Err(_) => jar.remove(Cookie::build(MEMBER_COOKIE).path("/")),
+    };
+
+    Ok((jar, back_home(&headers)))
+}
+
+pub async fn undo_event(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    role: Role,
+    Extension(csrf): Extension<CsrfToken>,
+) -> Result<Response, ErrorResponse> {
+    let event = app_state
         .db
         .undo_chore_event()
         .await
         .wrap_err("Failed to undo event")?;
-    Ok(Redirect::to(HOME_URI))
+    if let Some(event) = &event {
+        app_state.notify(Update::Chore(event.chore_id));
+    }
+    let chore_id = event.map(|event| event.chore_id);
+    respond_to_press(&app_state, chore_id, role, &csrf, &headers, &jar).await
 }
 
-pub async fn redo_event(State(app_state): State<AppState>) -> Result<Redirect, ErrorResponse> {
-    app_state
+pub async fn redo_event(
+    State(app_state): State<AppState>,
+    headers: HeaderMap,
+    jar: CookieJar,
+    role: Role,
+    Extension(csrf): Extension<CsrfToken>,
+) -> Result<Response, ErrorResponse> {
+    let event = app_state
         .db
         .redo_chore_event()
         .await
         .wrap_err("Failed to redo event")?;
-    Ok(Redirect::to(HOME_URI))
+    if let Some(event) = &event {
+        app_state.notify(Update::Chore(event.chore_id));
+    }
+    let chore_id = event.map(|event| event.chore_id);
+    respond_to_press(&app_state, chore_id, role, &csrf, &headers, &jar).await
+}
+
+/// The undo and redo buttons, or nothing for viewers, who can't use them
+fn render_undo_redo(
+    role: Role,
+    can_undo: bool,
+    can_redo: bool,
+    csrf: &CsrfToken,
+    lang: Lang,
+    l10n: &L10N,
+) -> Markup {
+    if !role.can_record() {
+        return html! {};
+    }
+    html! {
+        div #undo-redo .undo-redo {
+            @if can_undo {
+                form action=(UNDO_URI) method="POST" {
+                    (csrf)
+                    button type="submit" class="undo" {
+                        img src="/icons/undo.svg" alt=(l10n.translate(lang, "undo"));
+                    }
+                }
+            }
+            @if can_redo {
+                form action=(REDO_URI) method="POST" {
+                    (csrf)
+                    button type="submit" class="redo" {
+                        img src="/icons/redo.svg" alt=(l10n.translate(lang, "redo"));
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn render_room_nav(rooms: &[String], query: &HomeQuery, lang: Lang, l10n: &L10N) -> Markup {
+    let selected = |room: &str| {
+        query
+            .room
+            .as_ref()
+            .is_some_and(|selected| selected.eq_ignore_ascii_case(room))
+    };
+    html! {
+        nav.rooms {
+            a href=(HOME_URI) .selected[query.room.is_none() && !query.group] {
+                (l10n.translate(lang, "all-rooms"))
+            }
+            a href=(format!("{HOME_URI}?group=true")) .selected[query.room.is_none() && query.group] {
+                (l10n.translate(lang, "group-by-room"))
+            }
+            @for room in rooms {
+                a href=(room_uri(room)) .selected[selected(room)] { (room) }
+            }
+        }
+    }
+}
+
+fn render_member_select(
+    members: &[Member],
+    current_member: Option<MemberId>,
+    csrf: &CsrfToken,
+    lang: Lang,
+    l10n: &L10N,
+) -> Markup {
+    html! {
+        form.member-select action=(MEMBER_URI) method="POST" {
+            (csrf)
+            select name="member" onchange="this.form.submit()" title=(l10n.translate(lang, "completed-by")) {
+                option value="" selected[current_member.is_none()] { (l10n.translate(lang, "nobody")) }
+                @for member in members {
+                    option value=(member.id) selected[current_member == Some(member.id)] { (member.name) }
+                }
+            }
+            noscript {
+                button type="submit" { (l10n.translate(lang, "save")) }
+            }
+        }
+    }
 }
 
 #[tracing::instrument]
 fn time_until_next_chore(now: &Zoned, chore_event: &ChoreEvent) -> Span {
-    if chore_event.timestamp.is_none() {
-        return Span::new().microseconds(0);
+    match chore_event.next_due() {
+        Some(next_chore) => next_chore.since(now).expect("can calculate time since"),
+        None => Span::new().microseconds(0),
     }
-    let last_chore = chore_event.timestamp.as_ref().unwrap();
-    let interval = chore_event.interval;
-    let next_chore = last_chore.saturating_add(interval);
-    next_chore.since(now).expect("can calculate time since")
 }
 
 #[tracing::instrument]
@@ -166,18 +603,13 @@ fn sort_chores(mut chores: Vec<ChoreEvent>) -> Vec<ChoreEvent> {
 fn classify(
     now: &Zoned,
     next_due: &Zoned,
-    interval: &Span,
+    is_daily: bool,
     last_completed: &Option<Zoned>,
 ) -> &'static str {
-    let is_daily = interval
-        .total((Unit::Day, Zoned::now().date()))
-        .expect("can calculate total days")
-        < 1.0;
-
-    if let Some(last_completed) = last_completed {
-        if last_completed.date() == now.date() {
-            return "chore-done";
-        }
+    if let Some(last_completed) = last_completed
+        && last_completed.date() == now.date()
+    {
+        return "chore-done";
     }
 
     let due_days = next_due
@@ -198,8 +630,38 @@ fn classify(
     }
 }
 
+/// Inline style for a chore's card, carrying its accent colour if it has one
+fn chore_style(chore_event: &ChoreEvent) -> String {
+    let mut style = format!(
+        "view-transition-name: chore-event-{id}",
+        id = chore_event.id
+    );
+    if let Some(colour) = &chore_event.colour {
+        style.push_str(&format!("; --chore-accent: {colour}"));
+    }
+    style
+}
+
+/// A chore's emoji, or one of the built-in icons tinted with its accent colour
+pub fn render_chore_icon(icon: &str) -> Markup {
+    html! {
+        @if validation::CHORE_ICONS.contains(&icon) {
+            span.chore-icon.builtin-icon role="img" aria-label=(icon)
+                style=(format!("--icon: url(/icons/{icon}.svg)")) {}
+        } @else {
+            span.chore-icon role="img" { (icon) }
+        }
+    }
+}
+
 #[tracing::instrument]
-fn render_chore(chore_event: &ChoreEvent, lang: Lang, l10n: &L10N) -> Markup {
+fn render_chore(
+    chore_event: &ChoreEvent,
+    role: Role,
+    csrf: &CsrfToken,
+    lang: Lang,
+    l10n: &L10N,
+) -> Markup {
     let now = Zoned::now();
     let days_since_last = chore_event
         .timestamp
@@ -218,7 +680,7 @@ fn render_chore(chore_event: &ChoreEvent, lang: Lang, l10n: &L10N) -> Markup {
     let class = classify(
         &now,
         &now.saturating_add(next),
-        &chore_event.interval,
+        chore_event.schedule.is_daily(&chore_event.interval),
         &chore_event.timestamp,
     );
 
@@ -241,6 +703,8 @@ fn render_chore(chore_event: &ChoreEvent, lang: Lang, l10n: &L10N) -> Markup {
         }
     };
 
+    let tomorrow = now.date().tomorrow().unwrap_or(now.date());
+
     let days_since_last_prefix = l10n.translate_with(
         lang,
         "days-ago-prefix",
@@ -250,8 +714,12 @@ fn render_chore(chore_event: &ChoreEvent, lang: Lang, l10n: &L10N) -> Markup {
     );
 
     html! {
-        div.chore style=(format!("view-transition-name: chore-event-{id}", id=chore_event.id)) {
+        div.chore id=(format!("chore-{id}", id = chore_event.id)) style=(chore_style(chore_event)) {
             form action=(format!("/events/{id}", id=chore_event.id)) id=(format!("chore-form-{id}", id=chore_event.id)) class="chore-form" method="POST" {
+                (csrf)
+                @if let Some(icon) = &chore_event.icon {
+                    (render_chore_icon(icon))
+                }
                 p.name {
                     (chore_event.name)
                 }
@@ -262,22 +730,103 @@ fn render_chore(chore_event: &ChoreEvent, lang: Lang, l10n: &L10N) -> Markup {
                         ]))
                     }
                 }
-                button type="submit" class=(class) {
+                button type="submit" class=(class) disabled[!role.can_record()] {
                     (l10n.translate_with(lang, "days-ago-number", fluent_args![
                         "days" => days_since_last,
                     ]))
                 }
                 p.info {
-                    (l10n.translate_with(lang, "days-ago-suffix", fluent_args![
-                        "days" => days_since_last,
-                    ]))
-                    br;
-                    (next)
+                    a href=(chore_uri(chore_event.id)) {
+                        (l10n.translate_with(lang, "days-ago-suffix", fluent_args![
+                            "days" => days_since_last,
+                        ]))
+                        br;
+                        (next)
+                    }
                 }
                 div.spinner.hidden role="status" {
                     (PreEscaped(include_str!("./static_files/spinner.svg")));
                 }
             }
+            @if role.can_record() {
+                details.postpone {
+                    summary title=(l10n.translate(lang, "postpone")) { "⋯" }
+                    form action=(format!("/events/{id}/snooze", id=chore_event.id)) method="POST" {
+                        (csrf)
+                        input type="date" name="until" value=(tomorrow) min=(tomorrow) required;
+                        button type="submit" { (l10n.translate(lang, "snooze")) }
+                    }
+                    form action=(format!("/events/{id}/skip", id=chore_event.id)) method="POST" {
+                        (csrf)
+                        button type="submit" { (l10n.translate(lang, "skip")) }
+                    }
+                }
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chore_event() -> ChoreEvent {
+        ChoreEvent {
+            id: ChoreId(1),
+            name: "Dishes".to_string(),
+            interval: Span::new().days(1),
+            schedule: Default::default(),
+            room: None,
+            icon: None,
+            colour: None,
+            timestamp: Some(Zoned::now()),
+            postponed_until: None,
+            pauses: vec![],
+        }
+    }
+
+    #[test]
+    fn viewers_cannot_record_or_undo() {
+        let l10n = L10N::new();
+        let csrf = CsrfToken::new("token".to_string());
+
+        let viewer = render_chore(&chore_event(), Role::Viewer, &csrf, Lang::En, &l10n);
+        let viewer = viewer.into_string();
+        assert!(viewer.contains("disabled"));
+        assert!(!viewer.contains("/snooze"));
+        assert!(!viewer.contains("/skip"));
+        let undo_redo = render_undo_redo(Role::Viewer, true, true, &csrf, Lang::En, &l10n);
+        assert!(undo_redo.into_string().is_empty());
+
+        let member = render_chore(&chore_event(), Role::Member, &csrf, Lang::En, &l10n);
+        let member = member.into_string();
+        assert!(!member.contains("disabled"));
+        assert!(member.contains("/snooze"));
+        assert!(member.contains("/skip"));
+        let undo_redo = render_undo_redo(Role::Member, true, true, &csrf, Lang::En, &l10n);
+        let undo_redo = undo_redo.into_string();
+        assert!(undo_redo.contains(UNDO_URI));
+        assert!(undo_redo.contains(REDO_URI));
+    }
+
+    #[test]
+    fn pressed_chores_keep_their_csrf_token() {
+        let l10n = L10N::new();
+        let csrf = CsrfToken::new("token".to_string());
+        let chore = render_chore(&chore_event(), Role::Member, &csrf, Lang::En, &l10n);
+        let chore = chore.into_string();
+
+        let form_start = chore.find(r#"class="chore-form""#).unwrap();
+        let form_end = form_start + chore[form_start..].find("</form>").unwrap();
+        let token = chore
+            .find(r#"<input type="hidden" name="csrf_token" value="token">"#)
+            .unwrap();
+        assert!((form_start..form_end).contains(&token));
+
+        // the spinner only hides the rest of the form, so the token is
+        // still posted with it
+        assert!(!LOADING_SPINNER_JS.contains("removeChild"));
+        assert!(!LOADING_SPINNER_JS.contains(".remove()"));
+        assert!(LOADING_SPINNER_JS.contains("classList.add('hidden')"));
+    }
+}
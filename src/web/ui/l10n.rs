@@ -115,13 +115,13 @@ impl Lang {
 
         if let Some(header) = header {
             for lang in header.split(',') {
-                if let Some(lang) = lang.split(';').next() {
-                    if let Some(lang) = lang.split('-').next() {
-                        match lang.trim() {
-                            "*" | "en" => return Lang::En,
-                            "fr" => return Lang::Fr,
-                            _ => {}
-                        }
+                if let Some(lang) = lang.split(';').next()
+                    && let Some(lang) = lang.split('-').next()
+                {
+                    match lang.trim() {
+                        "*" | "en" => return Lang::En,
+                        "fr" => return Lang::Fr,
+                        _ => {}
                     }
                 }
             }
@@ -41,3 +41,116 @@ chore = Chore
 times-completed = Times Completed
 times-overdue = Times Overdue
 mean-days-overdue = Mean Days Overdue
+chore-history = History
+no-completions = This chore hasn't been completed yet.
+invalid-timestamp = Invalid date and time.
+back-to-manager = ← Back to Manage Chores
+schedule = Schedule
+schedule-placeholder = every interval
+invalid-schedule = Invalid schedule, leave empty or use e.g. “weekly: tu, th”, “monthly: 1, -1” or an RRULE that comes up at least once.
+members = Household Members
+new-member = New Member
+member-name-placeholder = Name
+invalid-member-name = Invalid member name, must not be blank and at most { $max } characters long.
+completed-by = Completed by
+nobody = Nobody
+effort = Effort
+invalid-effort = Effort must be a whole number between 1 and { $max }.
+household = Household
+member = Member
+share-of-completions = Share
+on-time-rate = On Time
+stats-window = Period
+stats-window-week = Last 7 days
+stats-window-month = Last 30 days
+stats-window-quarter = Last 90 days
+stats-window-year = Last 365 days
+stats-window-all = All time
+postpone = Snooze or skip
+snooze = Snooze
+skip = Skip
+snoozed-until = Snoozed until { $date }
+skipped = Skipped
+status = Status
+status-active = Active
+status-paused = Paused
+status-archived = Archived
+vacations = Vacations
+vacation-start = First day away
+vacation-end = Last day away
+vacation-dates = { $start } to { $end }
+vacation-since = Since { $start }
+end-vacation = End vacation
+on-vacation = On vacation, chores aren't falling due
+trash = Trash
+trash-empty = The trash is empty
+deleted-on = Deleted on { $date }
+restore = Restore
+purge = Delete forever
+room = Room
+room-placeholder = Kitchen
+invalid-room = Rooms must be at most { $max } characters long
+no-room = Everywhere else
+all-rooms = All chores
+group-by-room = By room
+icon = Icon
+colour = Colour
+use-colour = Use an accent colour
+invalid-icon = Icons must be an emoji or one of the built-in icons
+last-completed = Last completed
+next-due = Next due
+median-days-overdue = Median Days Overdue
+times-snoozed = Times Snoozed
+times-skipped = Times Skipped
+days-late-over-time = Days late over time
+completions = Completions
+completed-late = { $days ->
+    [1] 1 day late
+     *[other] { $days } days late
+}
+completed-early = { $days ->
+    [1] 1 day early
+     *[other] { $days } days early
+}
+completed-on-time = On time
+recorded-by = Recorded by { $user }
+edit-history = Edit history
+calendar = Calendar
+calendar-month = Month
+calendar-week = Week
+previous = Previous
+next = Next
+today = Today
+weekday-1 = Mon
+weekday-2 = Tue
+weekday-3 = Wed
+weekday-4 = Thu
+weekday-5 = Fri
+weekday-6 = Sat
+weekday-7 = Sun
+month-1 = January
+month-2 = February
+month-3 = March
+month-4 = April
+month-5 = May
+month-6 = June
+month-7 = July
+month-8 = August
+month-9 = September
+month-10 = October
+month-11 = November
+month-12 = December
+login = Log in
+username = Username
+password = Password
+log-in = Log in
+invalid-login = Wrong username or password
+log-out = Log out
+api-tokens = API tokens
+api-token-name-placeholder = What the token is for
+invalid-api-token = Give the token a name and at least one scope.
+api-token-created = Copy this token now, it won't be shown again:
+api-token-last-used = Last used { $date }
+api-token-never-used = Never used
+api-token-revoked-on = Revoked { $date }
+revoke = Revoke
@@ -41,3 +41,116 @@ chore = Corvée
 times-completed = Temps terminés
 times-overdue = Temps en retard
 mean-days-overdue = Nombre moyen de jours de retard
+chore-history = Historique
+no-completions = Cette corvée n'a pas encore été réalisée.
+invalid-timestamp = Date et heure non valides.
+back-to-manager = ← Retour à la gestion des tâches
+schedule = Calendrier
+schedule-placeholder = à chaque intervalle
+invalid-schedule = Calendrier non valide, laissez vide ou utilisez p. ex. « weekly: tu, th », « monthly: 1, -1 » ou une RRULE qui tombe au moins une fois.
+members = Membres du foyer
+new-member = Nouveau membre
+member-name-placeholder = Nom
+invalid-member-name = Nom de membre non valide, il ne doit pas être vide et doit contenir au plus { $max } caractères.
+completed-by = Fait par
+nobody = Personne
+effort = Effort
+invalid-effort = L'effort doit être un nombre entier entre 1 et { $max }.
+household = Foyer
+member = Membre
+share-of-completions = Part
+on-time-rate = À temps
+stats-window = Période
+stats-window-week = 7 derniers jours
+stats-window-month = 30 derniers jours
+stats-window-quarter = 90 derniers jours
+stats-window-year = 365 derniers jours
+stats-window-all = Depuis toujours
+postpone = Reporter ou passer
+snooze = Reporter
+skip = Passer
+snoozed-until = Reportée au { $date }
+skipped = Passée
+status = Statut
+status-active = Active
+status-paused = En pause
+status-archived = Archivée
+vacations = Vacances
+vacation-start = Premier jour d'absence
+vacation-end = Dernier jour d'absence
+vacation-dates = Du { $start } au { $end }
+vacation-since = Depuis le { $start }
+end-vacation = Terminer les vacances
+on-vacation = En vacances, les tâches ne sont pas dues
+trash = Corbeille
+trash-empty = La corbeille est vide
+deleted-on = Supprimée le { $date }
+restore = Restaurer
+purge = Supprimer définitivement
+room = Pièce
+room-placeholder = Cuisine
+invalid-room = Les pièces doivent faire au plus { $max } caractères
+no-room = Ailleurs
+all-rooms = Toutes les tâches
+group-by-room = Par pièce
+icon = Icône
+colour = Couleur
+use-colour = Utiliser une couleur d'accent
+invalid-icon = Les icônes doivent être un émoji ou une des icônes intégrées
+last-completed = Dernière fois
+next-due = Prochaine échéance
+median-days-overdue = Jours de retard médians
+times-snoozed = Fois reportée
+times-skipped = Fois passée
+days-late-over-time = Jours de retard au fil du temps
+completions = Réalisations
+completed-late = { $days ->
+    [1] 1 jour de retard
+     *[other] { $days } jours de retard
+}
+completed-early = { $days ->
+    [1] 1 jour d'avance
+     *[other] { $days } jours d'avance
+}
+completed-on-time = À l'heure
+recorded-by = Enregistré par { $user }
+edit-history = Modifier l'historique
+calendar = Calendrier
+calendar-month = Mois
+calendar-week = Semaine
+previous = Précédent
+next = Suivant
+today = Aujourd'hui
+weekday-1 = lun.
+weekday-2 = mar.
+weekday-3 = mer.
+weekday-4 = jeu.
+weekday-5 = ven.
+weekday-6 = sam.
+weekday-7 = dim.
+month-1 = janvier
+month-2 = février
+month-3 = mars
+month-4 = avril
+month-5 = mai
+month-6 = juin
+month-7 = juillet
+month-8 = août
+month-9 = septembre
+month-10 = octobre
+month-11 = novembre
+month-12 = décembre
+login = Connexion
+username = Nom d'utilisateur
+password = Mot de passe
+log-in = Se connecter
+invalid-login = Nom d'utilisateur ou mot de passe incorrect
+log-out = Se déconnecter
+api-tokens = Jetons d'API
+api-token-name-placeholder = À quoi sert le jeton
+invalid-api-token = Donnez un nom au jeton et au moins une portée.
+api-token-created = Copiez ce jeton maintenant, il ne sera plus affiché :
+api-token-last-used = Utilisé pour la dernière fois le { $date }
+api-token-never-used = Jamais utilisé
+api-token-revoked-on = Révoqué le { $date }
+revoke = Révoquer
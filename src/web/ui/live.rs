@@ -0,0 +1,40 @@
+use std::convert::Infallible;
+
+use axum::{
+    extract::State,
+    response::{
+        IntoResponse,
+        sse::{Event, KeepAlive, Sse},
+    },
+};
+use tokio_stream::{
+    StreamExt,
+    wrappers::{BroadcastStream, errors::BroadcastStreamRecvError},
+};
+
+use crate::web::{AppState, updates::Update};
+
+/// GET handler streaming chore updates to open home pages as server-sent
+/// events. A `chore` event carries the ID of the chore that changed, and a
+/// `chores` event means everything should be refreshed
+pub async fn live_updates(State(app_state): State<AppState>) -> impl IntoResponse {
+    let updates = BroadcastStream::new(app_state.updates.subscribe()).map(|update| {
+        let event = match update {
+            Ok(Update::Chore(chore_id)) => {
+                Event::default().event("chore").data(chore_id.to_string())
+            }
+            // a page that fell behind has missed something, so it catches up
+            // on everything
+            Ok(Update::Chores) | Err(BroadcastStreamRecvError::Lagged(_)) => {
+                Event::default().event("chores").data("all")
+            }
+        };
+        Ok::<_, Infallible>(event)
+    });
+
+    (
+        // stop nginx from buffering the stream
+        [("X-Accel-Buffering", "no")],
+        Sse::new(updates).keep_alive(KeepAlive::default()),
+    )
+}
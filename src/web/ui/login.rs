@@ -0,0 +1,186 @@
+use axum::{
+    Extension, Form,
+    extract::{Query, State, rejection::QueryRejection},
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Redirect, Response},
+};
+use axum_extra::extract::{CookieJar, cookie::Cookie};
+use color_eyre::eyre::WrapErr;
+use maud::{Markup, html};
+use serde::Deserialize;
+
+use crate::web::{
+    AppState,
+    auth::{
+        self,
+        csrf::{self, CsrfToken},
+        password, session,
+    },
+    ui::{
+        HOME_URI, LOGIN_URI,
+        error::ErrorResponse,
+        l10n::{L10N, Lang},
+        template,
+    },
+};
+
+#[derive(Deserialize, Default)]
+pub struct LoginQuery {
+    next: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct LoginForm {
+    username: String,
+    password: String,
+    #[serde(default)]
+    next: String,
+}
+
+/// Where to go after logging in. Only paths on this site are allowed, so the
+/// login page can't be used to send people elsewhere
+fn safe_next(next: &str) -> &str {
+    if next.starts_with('/') && !next.starts_with("//") && !next.contains('\\') {
+        next
+    } else {
+        HOME_URI
+    }
+}
+
+fn render_login(lang: Lang, l10n: &L10N, csrf: &CsrfToken, next: &str, failed: bool) -> Markup {
+    template::page(
+        lang,
+        &l10n.translate(lang, "login"),
+        html! {
+            main.login {
+                h1 { (l10n.translate(lang, "login")) }
+                @if failed {
+                    p.login-error { (l10n.translate(lang, "invalid-login")) }
+                }
+                form method="post" action=(LOGIN_URI) {
+                    (csrf)
+                    input type="hidden" name="next" value=(next);
+                    label {
+                        (l10n.translate(lang, "username"))
+                        input type="text" name="username" autocomplete="username" required autofocus;
+                    }
+                    label {
+                        (l10n.translate(lang, "password"))
+                        input type="password" name="password" autocomplete="current-password" required;
+                    }
+                    button type="submit" { (l10n.translate(lang, "log-in")) }
+                }
+            }
+        },
+    )
+}
+
+/// GET handler for the login page
+pub async fn login_page(
+    headers: HeaderMap,
+    jar: CookieJar,
+    State(app_state): State<AppState>,
+    Extension(csrf): Extension<CsrfToken>,
+    query: Result<Query<LoginQuery>, QueryRejection>,
+) -> Markup {
+    let query = query.map(|Query(query)| query).unwrap_or_default();
+    let accept_language = headers
+        .get("accept-language")
+        .and_then(|value| value.to_str().ok());
+    let lang = Lang::from_accept_language_header_and_cookie(accept_language, &jar);
+
+    render_login(
+        lang,
+        &app_state.l10n,
+        &csrf,
+        safe_next(query.next.as_deref().unwrap_or(HOME_URI)),
+        false,
+    )
+}
+
+/// POST handler to log in, starting a session if the password is right
+pub async fn log_in(
+    headers: HeaderMap,
+    jar: CookieJar,
+    State(app_state): State<AppState>,
+    Extension(csrf): Extension<CsrfToken>,
+    Form(form): Form<LoginForm>,
+) -> Result<Response, ErrorResponse> {
+    let user = app_state
+        .db
+        .get_user_for_login(&form.username)
+        .await
+        .wrap_err("Failed to look up user")?;
+    let (user, hash) = user.unzip();
+    let attempt = form.password.clone();
+    let verified =
+        tokio::task::spawn_blocking(move || password::verify_password(&attempt, hash.as_deref()))
+            .await
+            .wrap_err("Failed to verify password")?;
+
+    let Some(user) = user.filter(|_| verified) else {
+        tracing::info!("Failed login as {username}", username = form.username);
+        let accept_language = headers
+            .get("accept-language")
+            .and_then(|value| value.to_str().ok());
+        let lang = Lang::from_accept_language_header_and_cookie(accept_language, &jar);
+        return Ok((
+            StatusCode::UNAUTHORIZED,
+            render_login(lang, &app_state.l10n, &csrf, safe_next(&form.next), true),
+        )
+            .into_response());
+    };
+
+    let token = auth::new_token();
+    app_state
+        .db
+        .create_session(user.id, &auth::hash_token(&token), session::expires_at())
+        .await
+        .wrap_err_with(|| format!("Failed to start session for {}", user.username))?;
+    tracing::info!("{username} logged in", username = user.username);
+
+    // a new session gets a new CSRF token too
+    let jar = jar
+        .add(session::session_cookie(
+            token,
+            app_state.auth.secure_cookies,
+        ))
+        .add(csrf::csrf_cookie(
+            auth::new_token(),
+            app_state.auth.secure_cookies,
+        ));
+    Ok((jar, Redirect::to(safe_next(&form.next))).into_response())
+}
+
+/// POST handler to log out, ending the session everywhere it was used
+pub async fn log_out(
+    jar: CookieJar,
+    State(app_state): State<AppState>,
+) -> Result<impl IntoResponse, ErrorResponse> {
+    if let Some(cookie) = jar.get(session::SESSION_COOKIE) {
+        app_state
+            .db
+            .delete_session(&auth::hash_token(cookie.value()))
+            .await
+            .wrap_err("Failed to end session")?;
+    }
+    let jar = jar
+        .remove(Cookie::build(session::SESSION_COOKIE).path("/"))
+        .remove(Cookie::build(csrf::CSRF_COOKIE).path("/"));
+
+    Ok((jar, Redirect::to(LOGIN_URI)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_local_paths_are_followed_after_login() {
+        assert_eq!(safe_next("/calendar?view=week"), "/calendar?view=week");
+        assert_eq!(safe_next("https://example.com/"), HOME_URI);
+        assert_eq!(safe_next("//example.com/"), HOME_URI);
+        assert_eq!(safe_next("/\\example.com/"), HOME_URI);
+        assert_eq!(safe_next(""), HOME_URI);
+    }
+}
@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+
+use crate::{
+    db::Scope,
+    web::{
+        AppState,
+        auth::{self, csrf::CsrfToken},
+        ui::{error::ErrorResponse, l10n::Lang},
+        validation,
+    },
+};
+use axum::{Extension, Form, extract::State, http::HeaderMap};
+use axum_extra::extract::CookieJar;
+use color_eyre::eyre::WrapErr;
+use maud::Markup;
+use serde::Deserialize;
+
+use super::render::RenderErrors;
+
+#[derive(Deserialize)]
+pub struct RevokeApiTokenForm {
+    id: i64,
+}
+
+/// POST handler to create an API token. The token is shown once on the page
+/// that comes back, and only its hash is kept. The form has a `name` and a
+/// checkbox named after each scope
+pub async fn new_api_token(
+    headers: HeaderMap,
+    jar: CookieJar,
+    State(app_state): State<AppState>,
+    Extension(csrf): Extension<CsrfToken>,
+    Form(form): Form<HashMap<String, String>>,
+) -> Result<Markup, ErrorResponse> {
+    let name = form.get("name").map(String::as_str).unwrap_or_default();
+    let scopes: Vec<Scope> = Scope::ALL
+        .into_iter()
+        .filter(|scope| form.contains_key(scope.as_str()))
+        .collect();
+
+    let render_errors = if validation::is_valid_token_name(name) && !scopes.is_empty() {
+        let token = auth::new_token();
+        app_state
+            .db
+            .create_api_token(name.trim(), &auth::hash_token(&token), &scopes)
+            .await
+            .wrap_err("Failed to create API token")?;
+        RenderErrors {
+            new_api_token: Some(token),
+            ..Default::default()
+        }
+    } else {
+        RenderErrors {
+            create_api_token_has_error: true,
+            ..Default::default()
+        }
+    };
+
+    let accept_language = headers
+        .get("accept-language")
+        .and_then(|value| value.to_str().ok());
+    let lang = Lang::from_accept_language_header_and_cookie(accept_language, &jar);
+
+    Ok(
+        super::render::render(lang, &app_state, &csrf, Some(render_errors))
+            .await
+            .wrap_err("Failed to render new API token page")?,
+    )
+}
+
+/// POST handler to revoke an API token so it stops working
+pub async fn revoke_api_token(
+    headers: HeaderMap,
+    jar: CookieJar,
+    State(app_state): State<AppState>,
+    Extension(csrf): Extension<CsrfToken>,
+    Form(form): Form<RevokeApiTokenForm>,
+) -> Result<Markup, ErrorResponse> {
+    app_state
+        .db
+        .revoke_api_token(form.id)
+        .await
+        .wrap_err("Failed to revoke API token")?;
+
+    let accept_language = headers
+        .get("accept-language")
+        .and_then(|value| value.to_str().ok());
+    let lang = Lang::from_accept_language_header_and_cookie(accept_language, &jar);
+
+    Ok(super::render::render(lang, &app_state, &csrf, None)
+        .await
+        .wrap_err("Failed to render revoke API token page")?)
+}
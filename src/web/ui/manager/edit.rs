@@ -1,24 +1,39 @@
 use crate::{
-    db::Chore,
+    db::{Chore, ChoreStatus},
     web::{
         AppState,
+        auth::csrf::CsrfToken,
         ui::{error::ErrorResponse, l10n::Lang},
+        updates::Update,
+        validation,
     },
 };
-use axum::{Form, extract::State, http::HeaderMap};
+use axum::{Extension, Form, extract::State, http::HeaderMap};
 use axum_extra::extract::CookieJar;
 use color_eyre::eyre::WrapErr;
-use jiff::Span;
 use maud::Markup;
 use serde::Deserialize;
 
-use super::render::RenderErrors;
+use super::render::{EditErrors, RenderErrors};
 
 #[derive(Deserialize)]
 pub struct EditChoreForm {
     id: i64,
     name: String,
     interval: String,
+    #[serde(default)]
+    schedule: String,
+    #[serde(default)]
+    effort: String,
+    #[serde(default)]
+    room: String,
+    #[serde(default)]
+    icon: String,
+    #[serde(default)]
+    colour: String,
+    use_colour: Option<String>,
+    #[serde(default)]
+    status: ChoreStatus,
     save: Option<String>,
     delete: Option<String>,
 }
@@ -27,6 +42,7 @@ pub async fn edit_chore(
     headers: HeaderMap,
     jar: CookieJar,
     State(app_state): State<AppState>,
+    Extension(csrf): Extension<CsrfToken>,
     Form(form): Form<EditChoreForm>,
 ) -> Result<Markup, ErrorResponse> {
     let render_errors = if form.save.is_some() {
@@ -47,41 +63,74 @@ pub async fn edit_chore(
         .and_then(|value| value.to_str().ok());
     let lang = Lang::from_accept_language_header_and_cookie(accept_language, &jar);
 
-    Ok(super::render::render(lang, &app_state, render_errors)
-        .await
-        .wrap_err("Failed to render edit chore page")?)
+    Ok(
+        super::render::render(lang, &app_state, &csrf, render_errors)
+            .await
+            .wrap_err("Failed to render edit chore page")?,
+    )
 }
 
 async fn handle_save(
     app_state: &AppState,
     form: &EditChoreForm,
 ) -> Result<Option<RenderErrors>, ErrorResponse> {
-    let name_is_valid = !form.name.is_empty() && form.name.len() <= 160;
-    let interval: Option<Span> = form.interval.parse().ok();
-    let interval_is_valid = interval.is_some();
-    if !name_is_valid || !interval_is_valid {
+    let name_is_valid = validation::is_valid_chore_name(&form.name);
+    let interval = validation::parse_interval(&form.interval);
+    let schedule = validation::parse_schedule(&form.schedule);
+    let effort = validation::parse_effort(&form.effort);
+    let room = validation::parse_room(&form.room);
+    let icon = validation::parse_icon(&form.icon);
+    let colour = validation::parse_colour(match form.use_colour {
+        Some(_) => &form.colour,
+        None => "",
+    });
+    let (Some(interval), Some(schedule), Some(effort), Some(room), Some(icon), Some(colour), true) = (
+        interval,
+        schedule.clone(),
+        effort,
+        room.clone(),
+        icon.clone(),
+        colour.clone(),
+        name_is_valid,
+    ) else {
         return Ok(Some(RenderErrors {
-            edit_errors: Some((form.id.into(), !name_is_valid, !interval_is_valid)),
+            edit_errors: Some(EditErrors {
+                id: form.id.into(),
+                name: !name_is_valid,
+                interval: interval.is_none(),
+                schedule: schedule.is_none(),
+                effort: effort.is_none(),
+                room: room.is_none(),
+                icon: icon.is_none(),
+                colour: colour.is_none(),
+            }),
             ..Default::default()
         }));
-    }
-    let interval = interval.expect("interval is valid");
+    };
 
     let chore = Chore {
         id: form.id.into(),
         name: form.name.clone(),
         interval,
+        schedule,
+        effort,
+        status: form.status,
+        room,
+        icon,
+        colour,
     };
     app_state
         .db
         .update_chore(chore)
         .await
         .wrap_err("Failed to update chore")?;
+    app_state.notify(Update::Chore(form.id.into()));
 
     Ok(None)
 }
 
 async fn handle_delete(app_state: &AppState, form: &EditChoreForm) -> Result<(), ErrorResponse> {
     app_state.db.delete_chore(form.id.into()).await?;
+    app_state.notify(Update::Chores);
     Ok(())
 }
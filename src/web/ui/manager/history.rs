@@ -0,0 +1,281 @@
+use crate::{
+    db::{Chore, ChoreId, Event, EventId, EventKind, Member},
+    web::{
+        AppState,
+        auth::csrf::CsrfToken,
+        ui::{
+            MANAGER_URI,
+            error::ErrorResponse,
+            l10n::{L10N, Lang},
+            template,
+        },
+        updates::Update,
+    },
+};
+use axum::{
+    Extension, Form,
+    extract::{Path, State},
+    http::HeaderMap,
+    response::{IntoResponse, Redirect, Response},
+};
+use axum_extra::extract::CookieJar;
+use color_eyre::{Result, eyre::WrapErr};
+use fluent::fluent_args;
+use jiff::{civil::DateTime, tz::TimeZone};
+use maud::{Markup, html};
+use serde::Deserialize;
+
+/// The URI of the history page for a chore
+pub fn history_uri(chore_id: ChoreId) -> String {
+    format!("/manager/history/{chore_id}")
+}
+
+#[derive(Deserialize)]
+pub struct EditEventForm {
+    id: i64,
+    timestamp: String,
+    #[serde(default)]
+    member: String,
+    save: Option<String>,
+    delete: Option<String>,
+}
+
+/// GET handler for a chore's history page
+pub async fn chore_history(
+    headers: HeaderMap,
+    jar: CookieJar,
+    State(app_state): State<AppState>,
+    Extension(csrf): Extension<CsrfToken>,
+    Path(chore_id): Path<i64>,
+) -> Result<Response, ErrorResponse> {
+    let accept_language = headers
+        .get("accept-language")
+        .and_then(|value| value.to_str().ok());
+    let lang = Lang::from_accept_language_header_and_cookie(accept_language, &jar);
+
+    Ok(render(lang, &app_state, &csrf, chore_id.into(), None)
+        .await
+        .wrap_err("Failed to render chore history page")?)
+}
+
+/// POST handler to change the time of, or delete, a single event
+pub async fn edit_event(
+    headers: HeaderMap,
+    jar: CookieJar,
+    State(app_state): State<AppState>,
+    Extension(csrf): Extension<CsrfToken>,
+    Path(chore_id): Path<i64>,
+    Form(form): Form<EditEventForm>,
+) -> Result<Response, ErrorResponse> {
+    let chore_id = ChoreId::from(chore_id);
+    // only events of the chore whose page the form is on can be changed
+    let event = app_state
+        .db
+        .get_event(form.id.into())
+        .await
+        .wrap_err_with(|| format!("Failed to get event {id}", id = form.id))?
+        .filter(|event| event.chore_id == chore_id);
+    if event.is_none() {
+        tracing::warn!(
+            "Ignoring edit of event {id}, which isn't one of chore {chore_id}'s",
+            id = form.id
+        );
+    }
+
+    let mut invalid_event = None;
+    match event {
+        Some(event) if form.save.is_some() => {
+            let timestamp = form
+                .timestamp
+                .parse::<DateTime>()
+                .ok()
+                .and_then(|timestamp| timestamp.to_zoned(TimeZone::system()).ok());
+            match timestamp {
+                Some(timestamp) => {
+                    let event = Event {
+                        timestamp,
+                        member_id: form.member.parse::<i64>().ok().map(Into::into),
+                        ..event
+                    };
+                    app_state
+                        .db
+                        .update_event(event)
+                        .await
+                        .wrap_err_with(|| format!("Failed to update event {id}", id = form.id))?;
+                }
+                None => invalid_event = Some(event.id),
+            }
+        }
+        Some(event) if form.delete.is_some() => {
+            app_state
+                .db
+                .delete_event(event.id)
+                .await
+                .wrap_err_with(|| format!("Failed to delete event {id}", id = form.id))?;
+        }
+        _ => {}
+    }
+
+    if invalid_event.is_none() {
+        app_state.notify(Update::Chore(chore_id));
+    }
+
+    let accept_language = headers
+        .get("accept-language")
+        .and_then(|value| value.to_str().ok());
+    let lang = Lang::from_accept_language_header_and_cookie(accept_language, &jar);
+
+    Ok(render(lang, &app_state, &csrf, chore_id, invalid_event)
+        .await
+        .wrap_err("Failed to render chore history page")?)
+}
+
+fn render_event(
+    event: &Event,
+    members: &[Member],
+    has_error: bool,
+    lang: Lang,
+    l10n: &L10N,
+) -> Markup {
+    let form_id = format!("event-form-{id}", id = event.id);
+    let local = event
+        .timestamp
+        .with_time_zone(TimeZone::system())
+        .strftime("%Y-%m-%dT%H:%M:%S")
+        .to_string();
+
+    html! {
+        div.form-item {
+            input type="datetime-local" form=(form_id) .is-invalid[has_error] name="timestamp" value=(local) step="1" required;
+            span.form-item-error { (l10n.translate(lang, "invalid-timestamp")) }
+            @match (event.kind, &event.until) {
+                (EventKind::Snoozed, Some(until)) => {
+                    span.event-kind { (l10n.translate_with(lang, "snoozed-until", fluent_args![
+                        "date" => until.with_time_zone(TimeZone::system()).strftime("%Y-%m-%d").to_string(),
+                    ])) }
+                }
+                (EventKind::Skipped, _) => {
+                    span.event-kind { (l10n.translate(lang, "skipped")) }
+                }
+                _ => {}
+            }
+        }
+        div.form-item {
+            select form=(form_id) name="member" title=(l10n.translate(lang, "completed-by")) {
+                option value="" selected[event.member_id.is_none()] { (l10n.translate(lang, "nobody")) }
+                @for member in members {
+                    option value=(member.id) selected[event.member_id == Some(member.id)] { (member.name) }
+                }
+            }
+        }
+        div.form-item.form-item-button {
+            button type="submit"
+                form=(form_id)
+                name="save"
+                value="Save"
+                alt=(l10n.translate(lang, "save"))
+                title=(l10n.translate(lang, "save")) {
+                img src="/icons/save.svg" alt=(l10n.translate(lang, "save"));
+            }
+        }
+        div.form-item.form-item-button {
+            button type="submit"
+                form=(form_id)
+                name="delete"
+                value="Delete"
+                alt=(l10n.translate(lang, "delete"))
+                title=(l10n.translate(lang, "delete")) {
+                img src="/icons/trash.svg" alt=(l10n.translate(lang, "delete"));
+            }
+        }
+        hr;
+    }
+}
+
+fn render_page(
+    lang: Lang,
+    app_state: &AppState,
+    csrf: &CsrfToken,
+    chore: &Chore,
+    events: &[Event],
+    members: &[Member],
+    invalid_event: Option<EventId>,
+) -> Markup {
+    let l10n = &app_state.l10n;
+    let action = history_uri(chore.id);
+
+    template::page(
+        lang,
+        &format!(
+            "{name} · {history}",
+            name = chore.name,
+            history = l10n.translate(lang, "chore-history")
+        ),
+        html! {
+            main.manager {
+                h1 { (chore.name) }
+                fieldset {
+                    legend { (l10n.translate(lang, "chore-history")) }
+                    @for event in events {
+                        form id=(format!("event-form-{id}", id = event.id)) method="post" action=(action) {
+                            (csrf)
+                            input type="hidden" name="id" value=(event.id.0);
+                        }
+                    }
+                    @if events.is_empty() {
+                        p { (l10n.translate(lang, "no-completions")) }
+                    }
+                    div.event-list {
+                        @for event in events {
+                            (render_event(event, members, invalid_event == Some(event.id), lang, l10n))
+                        }
+                    }
+                }
+            }
+            footer {
+                { a href=(MANAGER_URI) { (l10n.translate(lang, "back-to-manager")) } }
+                { a href="/" { (l10n.translate(lang, "back-to-chores")) } }
+            }
+        },
+    )
+}
+
+async fn render(
+    lang: Lang,
+    app_state: &AppState,
+    csrf: &CsrfToken,
+    chore_id: ChoreId,
+    invalid_event: Option<EventId>,
+) -> Result<Response> {
+    let chore = app_state
+        .db
+        .get_chore(chore_id)
+        .await
+        .wrap_err_with(|| format!("Failed to get chore {chore_id}"))?;
+    let Some(chore) = chore else {
+        return Ok(Redirect::to(MANAGER_URI).into_response());
+    };
+
+    let events = app_state
+        .db
+        .get_chore_history(chore_id)
+        .await
+        .wrap_err_with(|| format!("Failed to get history for chore {chore_id}"))?;
+
+    let members = app_state
+        .db
+        .get_members()
+        .await
+        .wrap_err("Failed to get members")?;
+
+    Ok(render_page(
+        lang,
+        app_state,
+        csrf,
+        &chore,
+        &events,
+        &members,
+        invalid_event,
+    )
+    .into_response())
+}
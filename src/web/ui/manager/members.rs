@@ -0,0 +1,109 @@
+use crate::{
+    db::Member,
+    web::{
+        AppState,
+        auth::csrf::CsrfToken,
+        ui::{error::ErrorResponse, l10n::Lang},
+        validation,
+    },
+};
+use axum::{Extension, Form, extract::State, http::HeaderMap};
+use axum_extra::extract::CookieJar;
+use color_eyre::eyre::WrapErr;
+use maud::Markup;
+use serde::Deserialize;
+
+use super::render::RenderErrors;
+
+#[derive(Deserialize)]
+pub struct NewMemberForm {
+    name: String,
+}
+
+#[derive(Deserialize)]
+pub struct EditMemberForm {
+    id: i64,
+    name: String,
+    save: Option<String>,
+    delete: Option<String>,
+}
+
+/// POST handler to add a member to the household
+pub async fn new_member(
+    headers: HeaderMap,
+    jar: CookieJar,
+    State(app_state): State<AppState>,
+    Extension(csrf): Extension<CsrfToken>,
+    Form(form): Form<NewMemberForm>,
+) -> Result<Markup, ErrorResponse> {
+    let render_errors = if validation::is_valid_member_name(&form.name) {
+        app_state
+            .db
+            .create_member(&form.name)
+            .await
+            .wrap_err("Failed to create member")?;
+        None
+    } else {
+        Some(RenderErrors {
+            create_member_has_name_error: true,
+            ..Default::default()
+        })
+    };
+
+    let accept_language = headers
+        .get("accept-language")
+        .and_then(|value| value.to_str().ok());
+    let lang = Lang::from_accept_language_header_and_cookie(accept_language, &jar);
+
+    Ok(
+        super::render::render(lang, &app_state, &csrf, render_errors)
+            .await
+            .wrap_err("Failed to render new member page")?,
+    )
+}
+
+/// POST handler to rename or remove a member
+pub async fn edit_member(
+    headers: HeaderMap,
+    jar: CookieJar,
+    State(app_state): State<AppState>,
+    Extension(csrf): Extension<CsrfToken>,
+    Form(form): Form<EditMemberForm>,
+) -> Result<Markup, ErrorResponse> {
+    let mut render_errors = None;
+    if form.save.is_some() {
+        if validation::is_valid_member_name(&form.name) {
+            let member = Member {
+                id: form.id.into(),
+                name: form.name.clone(),
+            };
+            app_state
+                .db
+                .update_member(member)
+                .await
+                .wrap_err("Failed to update member")?;
+        } else {
+            render_errors = Some(RenderErrors {
+                edit_member_error: Some(form.id.into()),
+                ..Default::default()
+            });
+        }
+    } else if form.delete.is_some() {
+        app_state
+            .db
+            .delete_member(form.id.into())
+            .await
+            .wrap_err("Failed to delete member")?;
+    }
+
+    let accept_language = headers
+        .get("accept-language")
+        .and_then(|value| value.to_str().ok());
+    let lang = Lang::from_accept_language_header_and_cookie(accept_language, &jar);
+
+    Ok(
+        super::render::render(lang, &app_state, &csrf, render_errors)
+            .await
+            .wrap_err("Failed to render edit member page")?,
+    )
+}
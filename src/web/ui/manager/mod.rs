@@ -1,22 +1,33 @@
 use super::{error::ErrorResponse, l10n::Lang};
-use crate::web::AppState;
-use axum::{extract::State, http::HeaderMap};
+use crate::web::{AppState, auth::csrf::CsrfToken};
+use axum::{Extension, extract::State, http::HeaderMap};
 use axum_extra::extract::CookieJar;
 use color_eyre::Result;
 use maud::Markup;
 
+mod api_tokens;
 mod edit;
+mod history;
+mod members;
 mod new;
 mod render;
 mod settings;
+mod trash;
+mod vacations;
 
+pub use api_tokens::{new_api_token, revoke_api_token};
 pub use edit::edit_chore;
+pub use history::{chore_history, edit_event, history_uri};
+pub use members::{edit_member, new_member};
 pub use new::new_chore;
 pub use settings::change_language;
+pub use trash::edit_trash;
+pub use vacations::{edit_vacation, new_vacation};
 
 /// GET handler for the manager page
 pub async fn manager_home(
     State(app_state): State<AppState>,
+    Extension(csrf): Extension<CsrfToken>,
     headers: HeaderMap,
     jar: CookieJar,
 ) -> Result<Markup, ErrorResponse> {
@@ -25,7 +36,7 @@ pub async fn manager_home(
         .and_then(|value| value.to_str().ok());
     let lang = Lang::from_accept_language_header_and_cookie(accept_language, &jar);
 
-    render::render(lang, &app_state, Default::default())
+    render::render(lang, &app_state, &csrf, Default::default())
         .await
         .map_err(ErrorResponse::from)
 }
@@ -1,19 +1,36 @@
-use axum::{Form, extract::State, http::HeaderMap};
+use axum::{Extension, Form, extract::State, http::HeaderMap};
 use axum_extra::extract::CookieJar;
 use color_eyre::eyre::Context;
-use jiff::{Span, civil::Date, tz::TimeZone};
+use jiff::{civil::Date, tz::TimeZone};
 use maud::Markup;
 use serde::Deserialize;
 
-use crate::web::{
-    AppState,
-    ui::{error::ErrorResponse, l10n::Lang},
+use crate::{
+    db::{Chore, ChoreId, ChoreStatus, User},
+    web::{
+        AppState,
+        auth::csrf::CsrfToken,
+        ui::{self, error::ErrorResponse, l10n::Lang},
+        updates::Update,
+        validation,
+    },
 };
 
 #[derive(Deserialize)]
 pub struct NewChoreForm {
     name: String,
     interval: String,
+    #[serde(default)]
+    schedule: String,
+    #[serde(default)]
+    effort: String,
+    #[serde(default)]
+    room: String,
+    #[serde(default)]
+    icon: String,
+    #[serde(default)]
+    colour: String,
+    use_colour: Option<String>,
     history: Option<String>,
 }
 
@@ -21,37 +38,71 @@ pub async fn new_chore(
     headers: HeaderMap,
     jar: CookieJar,
     State(app_state): State<AppState>,
+    Extension(csrf): Extension<CsrfToken>,
+    user: Option<Extension<User>>,
     Form(form): Form<NewChoreForm>,
 ) -> Result<Markup, ErrorResponse> {
-    let name_is_valid = !form.name.is_empty() && form.name.len() <= 160;
-    let interval: Option<Span> = form.interval.parse().ok();
-    let interval_is_valid = interval.is_some();
+    let name_is_valid = validation::is_valid_chore_name(&form.name);
+    let interval = validation::parse_interval(&form.interval);
+    let schedule = validation::parse_schedule(&form.schedule);
+    let effort = validation::parse_effort(&form.effort);
+    let room = validation::parse_room(&form.room);
+    let icon = validation::parse_icon(&form.icon);
+    let colour = validation::parse_colour(match form.use_colour {
+        Some(_) => &form.colour,
+        None => "",
+    });
 
     let accept_language = headers
         .get("accept-language")
         .and_then(|value| value.to_str().ok());
     let lang = Lang::from_accept_language_header_and_cookie(accept_language, &jar);
 
-    if !name_is_valid || !interval_is_valid {
+    let (Some(interval), Some(schedule), Some(effort), Some(room), Some(icon), Some(colour), true) = (
+        interval,
+        schedule.clone(),
+        effort,
+        room.clone(),
+        icon.clone(),
+        colour.clone(),
+        name_is_valid,
+    ) else {
         return Ok(super::render::render(
             lang,
             &app_state,
+            &csrf,
             Some(super::render::RenderErrors {
                 create_has_name_error: !name_is_valid,
-                create_has_interval_error: !interval_is_valid,
+                create_has_interval_error: interval.is_none(),
+                create_has_schedule_error: schedule.is_none(),
+                create_has_effort_error: effort.is_none(),
+                create_has_room_error: room.is_none(),
+                create_has_icon_error: icon.is_none(),
+                create_has_colour_error: colour.is_none(),
                 ..Default::default()
             }),
         )
         .await?);
-    }
-    let interval = interval.expect("interval is valid");
-    let chore_id = match app_state.db.create_chore(&form.name, interval).await {
+    };
+    let chore = Chore {
+        id: ChoreId::default(),
+        name: form.name.clone(),
+        interval,
+        schedule,
+        effort,
+        status: ChoreStatus::Active,
+        room,
+        icon,
+        colour,
+    };
+    let chore_id = match app_state.db.create_chore(chore).await {
         Ok(id) => id,
         Err(e) => {
             tracing::warn!("Failed to create chore: {e:#?}");
             return Ok(super::render::render(
                 lang,
                 &app_state,
+                &csrf,
                 Some(super::render::RenderErrors {
                     create_created_ok: Some(false),
                     ..Default::default()
@@ -72,7 +123,7 @@ pub async fn new_chore(
 
             if let Err(e) = app_state
                 .db
-                .record_chore_event_when(chore_id, history)
+                .record_chore_event_when(chore_id, history, None, ui::home::user_id(user), None)
                 .await
             {
                 tracing::warn!("Failed to record chore event when creating a new chore: {e:#?}");
@@ -82,9 +133,12 @@ pub async fn new_chore(
         }
     }
 
+    app_state.notify(Update::Chores);
+
     Ok(super::render::render(
         lang,
         &app_state,
+        &csrf,
         Some(super::render::RenderErrors {
             create_created_ok: Some(true),
             ..Default::default()
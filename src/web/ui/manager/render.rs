@@ -1,38 +1,64 @@
 use crate::{
-    db::{Chore, ChoreId},
+    db::{ApiToken, Chore, ChoreId, ChoreStatus, Member, MemberId, Scope, TrashedChore, Vacation},
     web::{
         AppState,
+        auth::csrf::CsrfToken,
         ui::{
-            MANAGER_EDIT_URI, MANAGER_LANGUAGE_URI, MANAGER_NEW_URI,
+            MANAGER_API_TOKEN_NEW_URI, MANAGER_API_TOKEN_REVOKE_URI, MANAGER_EDIT_URI,
+            MANAGER_LANGUAGE_URI, MANAGER_MEMBER_EDIT_URI, MANAGER_MEMBER_NEW_URI, MANAGER_NEW_URI,
+            MANAGER_TRASH_URI, MANAGER_VACATION_EDIT_URI, MANAGER_VACATION_NEW_URI,
             l10n::{L10N, Lang},
             template,
         },
+        validation,
     },
 };
 use color_eyre::{Result, eyre::Context};
 use fluent::fluent_args;
+use jiff::{Zoned, tz::TimeZone};
 use maud::{Markup, PreEscaped, html};
 
-fn render_chore(
-    chore: &Chore,
-    has_name_error: bool,
-    has_interval_error: bool,
-    lang: Lang,
-    l10n: &L10N,
-) -> Markup {
+/// What colour pickers start at for chores without a colour
+const DEFAULT_COLOUR: &str = "#3a86ff";
+
+fn render_chore(chore: &Chore, errors: &EditErrors, lang: Lang, l10n: &L10N) -> Markup {
     html! {
         div.form-item {
-            input type="text" form=(format!("chore-form-{id}", id=chore.id.0)) .name-field .is-invalid[has_name_error] name="name" value=(chore.name) placeholder=(l10n.translate(lang, "name-placeholder")) required minlength="1" maxlength="160";
+            input type="text" form=(format!("chore-form-{id}", id=chore.id.0)) .name-field .is-invalid[errors.name] name="name" value=(chore.name) placeholder=(l10n.translate(lang, "name-placeholder")) required minlength="1" maxlength="160";
             span.form-item-error { (l10n.translate(lang, "invalid-chore-name")) }
         }
         div.form-item {
-            input type="text" form=(format!("chore-form-{id}", id=chore.id.0)) .interval-field .is-invalid[has_interval_error] name="interval" value=(format!("{interval:#}", interval = chore.interval)) placeholder="2w 4d" required minlength="2" maxlength="160";
+            input type="text" form=(format!("chore-form-{id}", id=chore.id.0)) .interval-field .is-invalid[errors.interval] name="interval" value=(format!("{interval:#}", interval = chore.interval)) placeholder="2w 4d" required minlength="2" maxlength="160";
             span.form-item-error {
                 (PreEscaped(l10n.translate_with(lang, "invalid-interval", fluent_args![
                                                 "link" => r#"<a href="https://docs.rs/jiff/latest/jiff/fmt/friendly/index.html" target="_blank">jiff::fmt::friendly ↗</a>"#,
                 ])))
             }
         }
+        div.form-item {
+            input type="text" form=(format!("chore-form-{id}", id=chore.id.0)) .schedule-field .is-invalid[errors.schedule] name="schedule" value=(chore.schedule) placeholder=(l10n.translate(lang, "schedule-placeholder")) maxlength="160";
+            span.form-item-error { (l10n.translate(lang, "invalid-schedule")) }
+        }
+        div.form-item {
+            input type="number" form=(format!("chore-form-{id}", id=chore.id.0)) .effort-field .is-invalid[errors.effort] name="effort" value=(chore.effort) title=(l10n.translate(lang, "effort")) required min="1" max=(validation::MAX_EFFORT);
+            span.form-item-error { (l10n.translate_with(lang, "invalid-effort", fluent_args!["max" => validation::MAX_EFFORT])) }
+        }
+        div.form-item {
+            input type="text" form=(format!("chore-form-{id}", id=chore.id.0)) .room-field .is-invalid[errors.room] name="room" value=[chore.room.as_deref()] list="rooms" placeholder=(l10n.translate(lang, "room-placeholder")) maxlength=(validation::MAX_ROOM_LEN);
+            span.form-item-error { (l10n.translate_with(lang, "invalid-room", fluent_args!["max" => validation::MAX_ROOM_LEN])) }
+        }
+        div.form-item.appearance-field {
+            (render_appearance_inputs(Some(&format!("chore-form-{id}", id=chore.id.0)), chore.icon.as_deref(), chore.colour.as_deref(), errors.icon || errors.colour, lang, l10n))
+        }
+        div.form-item {
+            select form=(format!("chore-form-{id}", id=chore.id.0)) name="status" title=(l10n.translate(lang, "status")) {
+                @for status in ChoreStatus::ALL {
+                    option value=(status.as_str()) selected[chore.status == status] {
+                        (l10n.translate(lang, format!("status-{status}", status = status.as_str())))
+                    }
+                }
+            }
+        }
         div.form-item.form-item-button {
             button type="submit"
                 form=(format!("chore-form-{id}", id=chore.id.0))
@@ -53,11 +79,37 @@ fn render_chore(
                 img src="/icons/trash.svg" alt=(l10n.translate(lang, "delete"));
             }
         }
+        div.form-item.form-item-button {
+            a.button-link href=(super::history::history_uri(chore.id))
+                title=(l10n.translate(lang, "chore-history")) {
+                img src="/icons/history.svg" alt=(l10n.translate(lang, "chore-history"));
+            }
+        }
         hr;
     }
 }
 
-fn render_chore_forms<I>(chores: I) -> Markup
+/// Inputs for a chore's icon and accent colour. The colour only counts if
+/// its checkbox is ticked, since colour pickers can't be left empty
+fn render_appearance_inputs(
+    form: Option<&str>,
+    icon: Option<&str>,
+    colour: Option<&str>,
+    is_invalid: bool,
+    lang: Lang,
+    l10n: &L10N,
+) -> Markup {
+    html! {
+        div.appearance-inputs .is-invalid[is_invalid] {
+            input type="text" form=[form] .icon-field name="icon" value=[icon] list="chore-icons" placeholder="🧹" title=(l10n.translate(lang, "icon")) maxlength="32";
+            input type="checkbox" form=[form] name="use_colour" value="on" checked[colour.is_some()] title=(l10n.translate(lang, "use-colour"));
+            input type="color" form=[form] name="colour" value=(colour.unwrap_or(DEFAULT_COLOUR)) title=(l10n.translate(lang, "colour"));
+        }
+        span.form-item-error { (l10n.translate(lang, "invalid-icon")) }
+    }
+}
+
+fn render_chore_forms<I>(chores: I, csrf: &CsrfToken) -> Markup
 where
     I: Iterator,
     I::Item: AsRef<Chore>,
@@ -65,18 +117,14 @@ where
     html!(
         @for chore in chores {
             form id=(format!("chore-form-{id}", id=chore.as_ref().id.0)) method="post" action=(MANAGER_EDIT_URI) {
+                (csrf)
                 input type="hidden" name="id" value=(chore.as_ref().id.0);
             }
         }
     )
 }
 
-fn render_chores<I>(
-    chores: I,
-    edit_errors: Option<(ChoreId, bool, bool)>,
-    lang: Lang,
-    l10n: &L10N,
-) -> Markup
+fn render_chores<I>(chores: I, edit_errors: Option<&EditErrors>, lang: Lang, l10n: &L10N) -> Markup
 where
     I: Iterator,
     I::Item: AsRef<Chore>,
@@ -85,41 +133,55 @@ where
         div.chore-list {
             @for chore in chores {
                 ({
-                    let (name_error, interval_error) = match edit_errors {
-                        Some((id, name_error, interval_error)) if id == chore.as_ref().id => (name_error, interval_error),
-                        _ => (false, false),
-                    };
-                    render_chore(chore.as_ref(), name_error, interval_error, lang, l10n)
+                    let no_errors = EditErrors::default();
+                    let errors = edit_errors
+                        .filter(|errors| errors.id == chore.as_ref().id)
+                        .unwrap_or(&no_errors);
+                    render_chore(chore.as_ref(), errors, lang, l10n)
                 })
             }
         }
     )
 }
 
-fn render_new_chore(
-    has_name_error: bool,
-    has_interval_error: bool,
-    created_ok: Option<bool>,
-    lang: Lang,
-    l10n: &L10N,
-) -> Markup {
+fn render_new_chore(errors: &RenderErrors, csrf: &CsrfToken, lang: Lang, l10n: &L10N) -> Markup {
     html! {
         form method="post" action=(MANAGER_NEW_URI) {
+            (csrf)
             div.chore-list {
                 div.form-item {
                     label for="name" { (l10n.translate(lang, "name")) }
-                    input type="text" .name-field .is-invalid[has_name_error] name="name" placeholder=(l10n.translate(lang, "name-placeholder")) required minlength="1" maxlength="160";
+                    input type="text" .name-field .is-invalid[errors.create_has_name_error] name="name" placeholder=(l10n.translate(lang, "name-placeholder")) required minlength="1" maxlength="160";
                     span.form-item-error { (l10n.translate(lang, "invalid-chore-name")) }
                 }
                 div.form-item {
                     label for="interval" { (l10n.translate(lang, "interval")) }
-                    input type="text" .interval-field .is-invalid[has_interval_error] name="interval" placeholder="2w 4d" required minlength="2" maxlength="160";
+                    input type="text" .interval-field .is-invalid[errors.create_has_interval_error] name="interval" placeholder="2w 4d" required minlength="2" maxlength="160";
                     span.form-item-error {
                         (PreEscaped(l10n.translate_with(lang, "invalid-interval", fluent_args![
                             "link" => r#"<a href="https://docs.rs/jiff/latest/jiff/fmt/friendly/index.html" target="_blank">jiff::fmt::friendly ↗</a>"#,
                         ])))
                     }
                 }
+                div.form-item {
+                    label for="schedule" { (l10n.translate(lang, "schedule")) }
+                    input type="text" .schedule-field .is-invalid[errors.create_has_schedule_error] name="schedule" placeholder=(l10n.translate(lang, "schedule-placeholder")) maxlength="160";
+                    span.form-item-error { (l10n.translate(lang, "invalid-schedule")) }
+                }
+                div.form-item {
+                    label for="effort" { (l10n.translate(lang, "effort")) }
+                    input type="number" .effort-field .is-invalid[errors.create_has_effort_error] name="effort" value="1" required min="1" max=(validation::MAX_EFFORT);
+                    span.form-item-error { (l10n.translate_with(lang, "invalid-effort", fluent_args!["max" => validation::MAX_EFFORT])) }
+                }
+                div.form-item {
+                    label for="room" { (l10n.translate(lang, "room")) }
+                    input type="text" .room-field .is-invalid[errors.create_has_room_error] name="room" list="rooms" placeholder=(l10n.translate(lang, "room-placeholder")) maxlength=(validation::MAX_ROOM_LEN);
+                    span.form-item-error { (l10n.translate_with(lang, "invalid-room", fluent_args!["max" => validation::MAX_ROOM_LEN])) }
+                }
+                div.form-item.appearance-field {
+                    label for="icon" { (l10n.translate(lang, "icon")) }
+                    (render_appearance_inputs(None, None, None, errors.create_has_icon_error || errors.create_has_colour_error, lang, l10n))
+                }
                 div.form-item {
                     label for="history" { (l10n.translate(lang, "history")) }
                     input type="date" name="history" id="history";
@@ -130,7 +192,7 @@ fn render_new_chore(
                         img src="/icons/new.svg" alt=(l10n.translate(lang, "create"));
                     }
                 }
-                @if let Some(created_ok) = created_ok {
+                @if let Some(created_ok) = errors.create_created_ok {
                     @if created_ok {
                         p { (l10n.translate(lang, "chore-created"))}
                     }
@@ -143,9 +205,185 @@ fn render_new_chore(
     }
 }
 
-fn render_language_select_form(lang: Lang, l10n: &L10N) -> Markup {
+fn render_members(
+    members: &[Member],
+    edit_error: Option<MemberId>,
+    create_has_error: bool,
+    csrf: &CsrfToken,
+    lang: Lang,
+    l10n: &L10N,
+) -> Markup {
+    html! {
+        @for member in members {
+            form id=(format!("member-form-{id}", id=member.id)) method="post" action=(MANAGER_MEMBER_EDIT_URI) {
+                (csrf)
+                input type="hidden" name="id" value=(member.id);
+            }
+        }
+        div.member-list {
+            @for member in members {
+                div.form-item {
+                    input type="text" form=(format!("member-form-{id}", id=member.id)) .name-field .is-invalid[edit_error == Some(member.id)] name="name" value=(member.name) required minlength="1" maxlength=(validation::MAX_MEMBER_NAME_LEN);
+                    span.form-item-error { (l10n.translate_with(lang, "invalid-member-name", fluent_args!["max" => validation::MAX_MEMBER_NAME_LEN])) }
+                }
+                div.form-item.form-item-button {
+                    button type="submit"
+                        form=(format!("member-form-{id}", id=member.id))
+                        name="save"
+                        value="Save"
+                        alt=(l10n.translate(lang, "save"))
+                        title=(l10n.translate(lang, "save")) {
+                        img src="/icons/save.svg" alt=(l10n.translate(lang, "save"));
+                    }
+                }
+                div.form-item.form-item-button {
+                    button type="submit"
+                        form=(format!("member-form-{id}", id=member.id))
+                        name="delete"
+                        value="Delete"
+                        alt=(l10n.translate(lang, "delete"))
+                        title=(l10n.translate(lang, "delete")) {
+                        img src="/icons/trash.svg" alt=(l10n.translate(lang, "delete"));
+                    }
+                }
+                hr;
+            }
+        }
+        form method="post" action=(MANAGER_MEMBER_NEW_URI) {
+            (csrf)
+            div.member-list {
+                div.form-item {
+                    label for="member-name" { (l10n.translate(lang, "new-member")) }
+                    input type="text" id="member-name" .name-field .is-invalid[create_has_error] name="name" placeholder=(l10n.translate(lang, "member-name-placeholder")) required minlength="1" maxlength=(validation::MAX_MEMBER_NAME_LEN);
+                    span.form-item-error { (l10n.translate_with(lang, "invalid-member-name", fluent_args!["max" => validation::MAX_MEMBER_NAME_LEN])) }
+                }
+                div.form-item.form-item-button {
+                    label for="submit" { (l10n.translate(lang, "create")) }
+                    button type="submit" alt=(l10n.translate(lang, "create")) title=(l10n.translate(lang, "create")) {
+                        img src="/icons/new.svg" alt=(l10n.translate(lang, "create"));
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn render_vacations(vacations: &[Vacation], csrf: &CsrfToken, lang: Lang, l10n: &L10N) -> Markup {
+    let now = Zoned::now();
+    html! {
+        @for vacation in vacations {
+            form id=(format!("vacation-form-{id}", id=vacation.id)) method="post" action=(MANAGER_VACATION_EDIT_URI) {
+                (csrf)
+                input type="hidden" name="id" value=(vacation.id);
+            }
+        }
+        div.vacation-list {
+            @for vacation in vacations {
+                span.vacation-dates .is-active[vacation.is_active(&now)] {
+                    @if let Some(end) = &vacation.end {
+                        (l10n.translate_with(lang, "vacation-dates", fluent_args![
+                            "start" => vacation.start.date().to_string(),
+                            "end" => end.date().to_string(),
+                        ]))
+                    } @else {
+                        (l10n.translate_with(lang, "vacation-since", fluent_args![
+                            "start" => vacation.start.date().to_string(),
+                        ]))
+                    }
+                }
+                div.form-item.form-item-button {
+                    @if vacation.end.as_ref().is_none_or(|end| *end > now) {
+                        button type="submit"
+                            form=(format!("vacation-form-{id}", id=vacation.id))
+                            name="end"
+                            value="End"
+                            title=(l10n.translate(lang, "end-vacation")) {
+                            img src="/icons/save.svg" alt=(l10n.translate(lang, "end-vacation"));
+                        }
+                    }
+                }
+                div.form-item.form-item-button {
+                    button type="submit"
+                        form=(format!("vacation-form-{id}", id=vacation.id))
+                        name="delete"
+                        value="Delete"
+                        alt=(l10n.translate(lang, "delete"))
+                        title=(l10n.translate(lang, "delete")) {
+                        img src="/icons/trash.svg" alt=(l10n.translate(lang, "delete"));
+                    }
+                }
+                hr;
+            }
+        }
+        form method="post" action=(MANAGER_VACATION_NEW_URI) {
+            (csrf)
+            div.vacation-list {
+                div.form-item {
+                    label for="vacation-start" { (l10n.translate(lang, "vacation-start")) }
+                    input type="date" id="vacation-start" name="start" required;
+                }
+                div.form-item {
+                    label for="vacation-end" { (l10n.translate(lang, "vacation-end")) }
+                    input type="date" id="vacation-end" name="end";
+                }
+                div.form-item.form-item-button {
+                    label for="submit" { (l10n.translate(lang, "create")) }
+                    button type="submit" alt=(l10n.translate(lang, "create")) title=(l10n.translate(lang, "create")) {
+                        img src="/icons/new.svg" alt=(l10n.translate(lang, "create"));
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn render_trash(trashed: &[TrashedChore], csrf: &CsrfToken, lang: Lang, l10n: &L10N) -> Markup {
+    html! {
+        @if trashed.is_empty() {
+            p.trash-empty { (l10n.translate(lang, "trash-empty")) }
+        }
+        @for trashed_chore in trashed {
+            form id=(format!("trash-form-{id}", id=trashed_chore.chore.id.0)) method="post" action=(MANAGER_TRASH_URI) {
+                (csrf)
+                input type="hidden" name="id" value=(trashed_chore.chore.id.0);
+            }
+        }
+        div.trash-list {
+            @for trashed_chore in trashed {
+                span { (trashed_chore.chore.name) }
+                span.deleted-at {
+                    (l10n.translate_with(lang, "deleted-on", fluent_args![
+                        "date" => trashed_chore.deleted_at.to_zoned(TimeZone::system()).date().to_string(),
+                    ]))
+                }
+                div.form-item.form-item-button {
+                    button type="submit"
+                        form=(format!("trash-form-{id}", id=trashed_chore.chore.id.0))
+                        name="restore"
+                        value="Restore"
+                        title=(l10n.translate(lang, "restore")) {
+                        img src="/icons/undo.svg" alt=(l10n.translate(lang, "restore"));
+                    }
+                }
+                div.form-item.form-item-button {
+                    button type="submit"
+                        form=(format!("trash-form-{id}", id=trashed_chore.chore.id.0))
+                        name="purge"
+                        value="Delete"
+                        title=(l10n.translate(lang, "purge")) {
+                        img src="/icons/trash.svg" alt=(l10n.translate(lang, "purge"));
+                    }
+                }
+                hr;
+            }
+        }
+    }
+}
+
+fn render_language_select_form(csrf: &CsrfToken, lang: Lang, l10n: &L10N) -> Markup {
     html! {
         form method="post" action=(MANAGER_LANGUAGE_URI) {
+            (csrf)
             div.language-select {
                 div.form-item {
                     label for="lang" { (l10n.translate(lang, "language")) }
@@ -165,17 +403,117 @@ fn render_language_select_form(lang: Lang, l10n: &L10N) -> Markup {
     }
 }
 
+fn render_api_tokens(
+    tokens: &[ApiToken],
+    new_token: Option<&str>,
+    create_has_error: bool,
+    csrf: &CsrfToken,
+    lang: Lang,
+    l10n: &L10N,
+) -> Markup {
+    let date = |at: jiff::Timestamp| at.to_zoned(TimeZone::system()).date().to_string();
+    html! {
+        @if let Some(new_token) = new_token {
+            p.new-api-token {
+                (l10n.translate(lang, "api-token-created"))
+                br;
+                code { (new_token) }
+            }
+        }
+        @for token in tokens {
+            form id=(format!("api-token-form-{id}", id=token.id)) method="post" action=(MANAGER_API_TOKEN_REVOKE_URI) {
+                (csrf)
+                input type="hidden" name="id" value=(token.id);
+            }
+        }
+        div.api-token-list {
+            @for token in tokens {
+                span { (token.name) }
+                span.api-token-scopes {
+                    (token.scopes.iter().map(Scope::as_str).collect::<Vec<_>>().join(" "))
+                }
+                span.api-token-used {
+                    @if let Some(revoked_at) = token.revoked_at {
+                        (l10n.translate_with(lang, "api-token-revoked-on", fluent_args!["date" => date(revoked_at)]))
+                    } @else if let Some(last_used_at) = token.last_used_at {
+                        (l10n.translate_with(lang, "api-token-last-used", fluent_args!["date" => date(last_used_at)]))
+                    } @else {
+                        (l10n.translate(lang, "api-token-never-used"))
+                    }
+                }
+                div.form-item.form-item-button {
+                    @if token.revoked_at.is_none() {
+                        button type="submit"
+                            form=(format!("api-token-form-{id}", id=token.id))
+                            value="Delete"
+                            title=(l10n.translate(lang, "revoke")) {
+                            img src="/icons/trash.svg" alt=(l10n.translate(lang, "revoke"));
+                        }
+                    }
+                }
+                hr;
+            }
+        }
+        form method="post" action=(MANAGER_API_TOKEN_NEW_URI) {
+            (csrf)
+            div.api-token-new {
+                div.form-item {
+                    input type="text" .is-invalid[create_has_error] name="name" placeholder=(l10n.translate(lang, "api-token-name-placeholder")) required minlength="1" maxlength=(validation::MAX_TOKEN_NAME_LEN);
+                    span.form-item-error { (l10n.translate(lang, "invalid-api-token")) }
+                }
+                div.api-token-scopes {
+                    @for scope in Scope::ALL {
+                        label {
+                            input type="checkbox" name=(scope.as_str());
+                            (scope.as_str())
+                        }
+                    }
+                }
+                div.form-item.form-item-button {
+                    button type="submit" alt=(l10n.translate(lang, "create")) title=(l10n.translate(lang, "create")) {
+                        img src="/icons/new.svg" alt=(l10n.translate(lang, "create"));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Which fields of an existing chore failed to save
+#[derive(Default)]
+pub struct EditErrors {
+    pub id: ChoreId,
+    pub name: bool,
+    pub interval: bool,
+    pub schedule: bool,
+    pub effort: bool,
+    pub room: bool,
+    pub icon: bool,
+    pub colour: bool,
+}
+
 #[derive(Default)]
 pub struct RenderErrors {
-    pub edit_errors: Option<(ChoreId, bool, bool)>,
+    pub edit_errors: Option<EditErrors>,
     pub create_has_name_error: bool,
     pub create_has_interval_error: bool,
+    pub create_has_schedule_error: bool,
+    pub create_has_effort_error: bool,
+    pub create_has_room_error: bool,
+    pub create_has_icon_error: bool,
+    pub create_has_colour_error: bool,
     pub create_created_ok: Option<bool>,
+    pub edit_member_error: Option<MemberId>,
+    pub create_member_has_name_error: bool,
+    pub create_api_token_has_error: bool,
+    /// A token that was just created, which can only be shown this once
+    pub new_api_token: Option<String>,
 }
 
 pub async fn render(
     lang: Lang,
     app_state: &AppState,
+    csrf: &CsrfToken,
     errors: Option<RenderErrors>,
 ) -> Result<Markup> {
     let chores = app_state
@@ -183,6 +521,31 @@ pub async fn render(
         .get_all_chores()
         .await
         .wrap_err("Failed to get chores")?;
+    let members = app_state
+        .db
+        .get_members()
+        .await
+        .wrap_err("Failed to get members")?;
+    let rooms = app_state
+        .db
+        .get_rooms()
+        .await
+        .wrap_err("Failed to get rooms")?;
+    let trashed = app_state
+        .db
+        .get_trashed_chores()
+        .await
+        .wrap_err("Failed to get trashed chores")?;
+    let vacations = app_state
+        .db
+        .get_vacations()
+        .await
+        .wrap_err("Failed to get vacations")?;
+    let api_tokens = app_state
+        .db
+        .get_api_tokens()
+        .await
+        .wrap_err("Failed to get API tokens")?;
     let errors = errors.unwrap_or_default();
 
     Ok(template::page(
@@ -193,22 +556,56 @@ pub async fn render(
                 h1 style="view-transition-name: manage-header" {
                     (app_state.l10n.translate(lang, "manage-chores"))
                 }
+                datalist id="rooms" {
+                    @for room in &rooms {
+                        option value=(room) {}
+                    }
+                }
+                datalist id="chore-icons" {
+                    @for icon in validation::CHORE_ICONS {
+                        option value=(icon) {}
+                    }
+                }
                 fieldset {
                     legend { (app_state.l10n.translate(lang, "new-chore")) }
-                    (render_new_chore(
-                            errors.create_has_name_error,
-                            errors.create_has_interval_error,
-                            errors.create_created_ok,
-                            lang, &app_state.l10n))
+                    (render_new_chore(&errors, csrf, lang, &app_state.l10n))
                 }
                 fieldset {
                     legend { (app_state.l10n.translate(lang, "chores")) }
-                    (render_chore_forms(chores.iter()))
-                    (render_chores(chores.iter(), errors.edit_errors, lang, &app_state.l10n))
+                    (render_chore_forms(chores.iter(), csrf))
+                    (render_chores(chores.iter(), errors.edit_errors.as_ref(), lang, &app_state.l10n))
+                }
+                fieldset {
+                    legend { (app_state.l10n.translate(lang, "members")) }
+                    (render_members(
+                        &members,
+                        errors.edit_member_error,
+                        errors.create_member_has_name_error,
+                        csrf,
+                        lang,
+                        &app_state.l10n))
+                }
+                fieldset {
+                    legend { (app_state.l10n.translate(lang, "vacations")) }
+                    (render_vacations(&vacations, csrf, lang, &app_state.l10n))
+                }
+                fieldset {
+                    legend { (app_state.l10n.translate(lang, "trash")) }
+                    (render_trash(&trashed, csrf, lang, &app_state.l10n))
                 }
                 fieldset {
                     legend { (app_state.l10n.translate(lang, "settings")) }
-                    (render_language_select_form(lang, &app_state.l10n))
+                    (render_language_select_form(csrf, lang, &app_state.l10n))
+                }
+                fieldset {
+                    legend { (app_state.l10n.translate(lang, "api-tokens")) }
+                    (render_api_tokens(
+                        &api_tokens,
+                        errors.new_api_token.as_deref(),
+                        errors.create_api_token_has_error,
+                        csrf,
+                        lang,
+                        &app_state.l10n))
                 }
             }
             footer {
@@ -3,7 +3,10 @@ use axum::{
     Form,
     response::{IntoResponse, Redirect},
 };
-use axum_extra::extract::{CookieJar, cookie::Cookie};
+use axum_extra::extract::{
+    CookieJar,
+    cookie::{Cookie, SameSite},
+};
 use serde::Deserialize;
 
 #[derive(Deserialize)]
@@ -20,6 +23,7 @@ pub async fn change_language(
         Cookie::build(("lang", lang.to_string()))
             .path("/")
             .http_only(true)
+            .same_site(SameSite::Lax)
             .build(),
     );
 
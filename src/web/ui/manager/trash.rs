@@ -0,0 +1,51 @@
+use crate::web::{
+    AppState,
+    auth::csrf::CsrfToken,
+    ui::{error::ErrorResponse, l10n::Lang},
+    updates::Update,
+};
+use axum::{Extension, Form, extract::State, http::HeaderMap};
+use axum_extra::extract::CookieJar;
+use color_eyre::eyre::WrapErr;
+use maud::Markup;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct TrashForm {
+    id: i64,
+    restore: Option<String>,
+    purge: Option<String>,
+}
+
+/// POST handler to restore a chore from the trash or purge it for good
+pub async fn edit_trash(
+    headers: HeaderMap,
+    jar: CookieJar,
+    State(app_state): State<AppState>,
+    Extension(csrf): Extension<CsrfToken>,
+    Form(form): Form<TrashForm>,
+) -> Result<Markup, ErrorResponse> {
+    if form.restore.is_some() {
+        app_state
+            .db
+            .restore_chore(form.id.into())
+            .await
+            .wrap_err("Failed to restore chore")?;
+        app_state.notify(Update::Chores);
+    } else if form.purge.is_some() {
+        app_state
+            .db
+            .purge_chore(form.id.into())
+            .await
+            .wrap_err("Failed to purge chore")?;
+    }
+
+    let accept_language = headers
+        .get("accept-language")
+        .and_then(|value| value.to_str().ok());
+    let lang = Lang::from_accept_language_header_and_cookie(accept_language, &jar);
+
+    Ok(super::render::render(lang, &app_state, &csrf, None)
+        .await
+        .wrap_err("Failed to render trash page")?)
+}
@@ -0,0 +1,103 @@
+use crate::web::{
+    AppState,
+    auth::csrf::CsrfToken,
+    ui::{error::ErrorResponse, l10n::Lang},
+    updates::Update,
+};
+use axum::{Extension, Form, extract::State, http::HeaderMap};
+use axum_extra::extract::CookieJar;
+use color_eyre::eyre::WrapErr;
+use jiff::{Zoned, civil::Date, tz::TimeZone};
+use maud::Markup;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct NewVacationForm {
+    start: Date,
+    #[serde(default)]
+    end: String,
+}
+
+#[derive(Deserialize)]
+pub struct EditVacationForm {
+    id: i64,
+    end: Option<String>,
+    delete: Option<String>,
+}
+
+/// POST handler to plan a vacation. The end date is the last day away, and
+/// leaving it empty keeps the vacation going until it is ended
+pub async fn new_vacation(
+    headers: HeaderMap,
+    jar: CookieJar,
+    State(app_state): State<AppState>,
+    Extension(csrf): Extension<CsrfToken>,
+    Form(form): Form<NewVacationForm>,
+) -> Result<Markup, ErrorResponse> {
+    let start = form
+        .start
+        .to_zoned(TimeZone::system())
+        .wrap_err("Failed to create vacation start timestamp")?;
+    let end = match form.end.parse::<Date>() {
+        Ok(end) => Some(
+            end.tomorrow()
+                .and_then(|end| end.to_zoned(TimeZone::system()))
+                .wrap_err("Failed to create vacation end timestamp")?,
+        ),
+        Err(_) => None,
+    };
+
+    if end.as_ref().is_some_and(|end| *end <= start) {
+        tracing::warn!("Not creating a vacation that ends before it starts");
+    } else {
+        app_state
+            .db
+            .create_vacation(&start, end.as_ref())
+            .await
+            .wrap_err("Failed to create vacation")?;
+        app_state.notify(Update::Chores);
+    }
+
+    let accept_language = headers
+        .get("accept-language")
+        .and_then(|value| value.to_str().ok());
+    let lang = Lang::from_accept_language_header_and_cookie(accept_language, &jar);
+
+    Ok(super::render::render(lang, &app_state, &csrf, None)
+        .await
+        .wrap_err("Failed to render new vacation page")?)
+}
+
+/// POST handler to end a vacation now or remove it altogether
+pub async fn edit_vacation(
+    headers: HeaderMap,
+    jar: CookieJar,
+    State(app_state): State<AppState>,
+    Extension(csrf): Extension<CsrfToken>,
+    Form(form): Form<EditVacationForm>,
+) -> Result<Markup, ErrorResponse> {
+    if form.end.is_some() {
+        app_state
+            .db
+            .end_vacation(form.id, &Zoned::now())
+            .await
+            .wrap_err("Failed to end vacation")?;
+        app_state.notify(Update::Chores);
+    } else if form.delete.is_some() {
+        app_state
+            .db
+            .delete_vacation(form.id)
+            .await
+            .wrap_err("Failed to delete vacation")?;
+        app_state.notify(Update::Chores);
+    }
+
+    let accept_language = headers
+        .get("accept-language")
+        .and_then(|value| value.to_str().ok());
+    let lang = Lang::from_accept_language_header_and_cookie(accept_language, &jar);
+
+    Ok(super::render::render(lang, &app_state, &csrf, None)
+        .await
+        .wrap_err("Failed to render edit vacation page")?)
+}
@@ -4,6 +4,7 @@ use axum::{
     Router,
     body::Body,
     http::{Response, StatusCode},
+    middleware,
     response::IntoResponse,
     routing::{get, post},
 };
@@ -11,44 +12,127 @@ use l10n::Lang;
 use maud::html;
 use tower_http::catch_panic::CatchPanicLayer;
 
-use super::AppState;
+use super::{AppState, auth};
+use crate::db::Role;
 
 pub mod cache;
-mod error;
+mod calendar;
+mod chore;
+pub mod error;
 mod home;
 pub mod l10n;
+mod live;
+mod login;
 mod manager;
+mod offline;
 mod static_files;
 mod stats;
 mod template;
 
 static HOME_URI: &str = "/";
 static STATS_URI: &str = "/stats";
+static CALENDAR_URI: &str = "/calendar";
+static CHORE_URI: &str = "/chore/{chore_id}";
 static EVENT_URI: &str = "/events/{chore_id}";
+static SNOOZE_URI: &str = "/events/{chore_id}/snooze";
+static SKIP_URI: &str = "/events/{chore_id}/skip";
+static QUEUED_EVENT_URI: &str = "/events/{chore_id}/queued";
 static UNDO_URI: &str = "/events/undo";
 static REDO_URI: &str = "/events/redo";
+static UPDATES_URI: &str = "/updates";
 static MANAGER_URI: &str = "/manager";
 static MANAGER_EDIT_URI: &str = "/manager/edit";
 static MANAGER_NEW_URI: &str = "/manager/new";
+static MANAGER_HISTORY_URI: &str = "/manager/history/{chore_id}";
+static MANAGER_MEMBER_EDIT_URI: &str = "/manager/members/edit";
+static MANAGER_MEMBER_NEW_URI: &str = "/manager/members/new";
+static MANAGER_TRASH_URI: &str = "/manager/trash";
+static MANAGER_VACATION_EDIT_URI: &str = "/manager/vacations/edit";
+static MANAGER_VACATION_NEW_URI: &str = "/manager/vacations/new";
+static MEMBER_URI: &str = "/member";
 static MANAGER_LANGUAGE_URI: &str = "/manager/settings/language";
+static MANAGER_API_TOKEN_NEW_URI: &str = "/manager/settings/tokens/new";
+static MANAGER_API_TOKEN_REVOKE_URI: &str = "/manager/settings/tokens/revoke";
 static STYLES_URI: &str = "/styles.css";
+pub(super) static LOGIN_URI: &str = "/login";
+static LOGOUT_URI: &str = "/logout";
+
+/// Percent-encode a value for use in a query string
+fn percent_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~".contains(&byte) {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    encoded
+}
+
+/// Where to log in, coming back to `next` afterwards
+pub(super) fn login_uri(next: &str) -> String {
+    format!("{LOGIN_URI}?next={next}", next = percent_encode(next))
+}
 
 pub fn routes() -> Router<AppState> {
-    Router::new()
+    // anyone who is logged in can look around
+    let viewer = Router::new()
         .route(HOME_URI, get(home::home))
         .route(STATS_URI, get(stats::stats_page))
+        .route(CALENDAR_URI, get(calendar::calendar_page))
+        .route(CHORE_URI, get(chore::chore_page))
+        .route(UPDATES_URI, get(live::live_updates))
+        .route(LOGIN_URI, get(login::login_page).post(login::log_in))
+        .route(LOGOUT_URI, post(login::log_out))
+        .route(STYLES_URI, get(static_files::styles))
+        .route("/icons/{icon}", get(static_files::svg_icon))
+        .route("/manifest.json", get(static_files::manifest))
+        .route("/service-worker.js", get(static_files::service_worker))
+        .route("/icon.png", get(static_files::app_icon))
+        .route("/favicon.ico", get(static_files::favicon));
+
+    let member = Router::new()
         .route(UNDO_URI, post(home::undo_event))
         .route(REDO_URI, post(home::redo_event))
         .route(EVENT_URI, post(home::record_event))
+        .route(SNOOZE_URI, post(home::snooze_chore))
+        .route(SKIP_URI, post(home::skip_chore))
+        .route(QUEUED_EVENT_URI, post(offline::record_queued_event))
+        .route(MEMBER_URI, post(home::select_member))
+        .route_layer(middleware::from_fn_with_state(
+            Role::Member,
+            auth::require_role,
+        ));
+
+    let admin = Router::new()
         .route(MANAGER_URI, get(manager::manager_home))
         .route(MANAGER_EDIT_URI, post(manager::edit_chore))
         .route(MANAGER_NEW_URI, post(manager::new_chore))
+        .route(
+            MANAGER_HISTORY_URI,
+            get(manager::chore_history).post(manager::edit_event),
+        )
+        .route(MANAGER_MEMBER_EDIT_URI, post(manager::edit_member))
+        .route(MANAGER_MEMBER_NEW_URI, post(manager::new_member))
+        .route(MANAGER_TRASH_URI, post(manager::edit_trash))
+        .route(MANAGER_VACATION_EDIT_URI, post(manager::edit_vacation))
+        .route(MANAGER_VACATION_NEW_URI, post(manager::new_vacation))
         .route(MANAGER_LANGUAGE_URI, post(manager::change_language))
-        .route(STYLES_URI, get(static_files::styles))
-        .route("/icons/{icon}", get(static_files::svg_icon))
-        .route("/manifest.json", get(static_files::manifest))
-        .route("/icon.png", get(static_files::app_icon))
-        .route("/favicon.ico", get(static_files::favicon))
+        .route(MANAGER_API_TOKEN_NEW_URI, post(manager::new_api_token))
+        .route(
+            MANAGER_API_TOKEN_REVOKE_URI,
+            post(manager::revoke_api_token),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            Role::Admin,
+            auth::require_role,
+        ));
+
+    Router::new()
+        .merge(viewer)
+        .merge(member)
+        .merge(admin)
         .layer(CatchPanicLayer::custom(handle_panic))
         .fallback(handler_404)
 }
@@ -75,6 +159,24 @@ fn handle_panic(_err: Box<dyn Any + Send + 'static>) -> Response<Body> {
         .unwrap()
 }
 
+/// What someone sees when their role doesn't allow what they tried to do
+pub(super) fn forbidden_page() -> impl IntoResponse {
+    (
+        StatusCode::FORBIDDEN,
+        template::page(
+            Lang::En,
+            "403 Forbidden",
+            html! {
+                main {
+                    h1 { "403 Forbidden" }
+                    p { "You aren't allowed to do that." }
+                    p { a href=(HOME_URI) { "Back to chores" } }
+                }
+            },
+        ),
+    )
+}
+
 async fn handler_404() -> impl IntoResponse {
     (
         StatusCode::NOT_FOUND,
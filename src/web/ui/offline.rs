@@ -0,0 +1,88 @@
+use axum::{
+    Extension, Form,
+    extract::{Path, State},
+    http::StatusCode,
+};
+use axum_extra::extract::CookieJar;
+use color_eyre::eyre::WrapErr;
+use jiff::{Timestamp, tz::TimeZone};
+use serde::Deserialize;
+
+use crate::{
+    db::{ChoreId, User},
+    web::{AppState, ui::error::ErrorResponse, updates::Update, validation},
+};
+
+#[derive(Deserialize)]
+pub struct QueuedEventForm {
+    /// When the button was pressed
+    timestamp: String,
+    /// Picked by the service worker when it queued the press
+    key: String,
+}
+
+/// When a queued press happened. A device with its clock set ahead can't
+/// complete chores in the future, so those count as happening `now`
+fn pressed_at(timestamp: &str, now: Timestamp) -> Option<Timestamp> {
+    let timestamp = timestamp.parse::<Timestamp>().ok()?;
+    Some(timestamp.min(now))
+}
+
+/// POST handler for chore button presses that the service worker queued
+/// while offline and is now replaying. Presses keep the time they were made
+/// at, and replaying one that was already recorded does nothing
+pub async fn record_queued_event(
+    State(app_state): State<AppState>,
+    Path(chore_id): Path<i64>,
+    jar: CookieJar,
+    user: Option<Extension<User>>,
+    Form(form): Form<QueuedEventForm>,
+) -> Result<StatusCode, ErrorResponse> {
+    let chore_id = ChoreId(chore_id);
+    let Some(timestamp) = pressed_at(&form.timestamp, Timestamp::now()) else {
+        tracing::warn!(
+            "Ignoring queued event with invalid timestamp {}",
+            form.timestamp
+        );
+        return Ok(StatusCode::BAD_REQUEST);
+    };
+    if !validation::is_valid_idempotency_key(&form.key) {
+        tracing::warn!("Ignoring queued event with invalid key");
+        return Ok(StatusCode::BAD_REQUEST);
+    }
+
+    let member_id = super::home::current_member(&app_state, &jar).await?;
+    let event = app_state
+        .db
+        .record_chore_event_when(
+            chore_id,
+            timestamp.to_zoned(TimeZone::system()),
+            member_id,
+            super::home::user_id(user),
+            Some(&form.key),
+        )
+        .await
+        .wrap_err_with(|| format!("Failed to record queued event for chore {chore_id}"))?;
+    if event.is_none() {
+        return Ok(StatusCode::NOT_FOUND);
+    }
+    app_state.notify(Update::Chore(chore_id));
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn presses_cannot_happen_in_the_future() {
+        let now: Timestamp = "2025-06-01T12:00:00Z".parse().unwrap();
+        assert_eq!(
+            pressed_at("2025-06-01T11:00:00Z", now),
+            Some("2025-06-01T11:00:00Z".parse().unwrap())
+        );
+        assert_eq!(pressed_at("2025-06-02T12:00:00Z", now), Some(now));
+        assert_eq!(pressed_at("yesterday", now), None);
+    }
+}
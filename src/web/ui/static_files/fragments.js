@@ -0,0 +1,58 @@
+(function () {
+    // press chore, undo and redo buttons in the background and swap in just
+    // the parts of the page that changed. Without script the forms post as
+    // usual and are redirected back to the whole page
+    document.addEventListener('submit', async function (event) {
+        const form = event.target;
+        if (!form.matches('form.chore-form, .undo-redo form')) {
+            return;
+        }
+        event.preventDefault();
+
+        const token = form.querySelector('input[name="csrf_token"]');
+        let response;
+        try {
+            response = await fetch(form.action, {
+                method: 'POST',
+                headers: {
+                    'X-Fragment': 'true',
+                    'X-CSRF-Token': token ? token.value : '',
+                },
+                body: new URLSearchParams(new FormData(form)),
+            });
+        } catch (e) {
+            // let the server decide what to show instead
+            form.submit();
+            return;
+        }
+        if (response.status === 202) {
+            // the service worker queued the press until we're back online, so
+            // posting the form again would record it twice
+            const chore = form.closest('.chore');
+            if (chore) {
+                chore.classList.add('queued');
+            }
+            return;
+        }
+        if (!response.ok || response.redirected) {
+            // redirected to log in, most likely
+            form.submit();
+            return;
+        }
+
+        let fresh;
+        try {
+            fresh = new DOMParser().parseFromString(await response.text(), 'text/html');
+        } catch (e) {
+            location.reload();
+            return;
+        }
+
+        for (const element of Array.from(fresh.body.children)) {
+            const current = element.id ? document.getElementById(element.id) : null;
+            if (current) {
+                current.replaceWith(document.importNode(element, true));
+            }
+        }
+    });
+})();
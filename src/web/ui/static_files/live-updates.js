@@ -0,0 +1,82 @@
+(function () {
+    // keep the page in step with chores done on other devices by listening
+    // for updates from the server and re-rendering what changed
+    if (!('EventSource' in window)) {
+        setTimeout(function () {
+            location.reload();
+        }, 5 * 60 * 1000);
+        return;
+    }
+
+    // chore IDs waiting to be refreshed, or 'all'
+    let pending = new Set();
+    let timer = null;
+
+    function queue(id) {
+        pending.add(id);
+        if (timer === null) {
+            // updates tend to come in bursts, so refresh once per burst
+            timer = setTimeout(refresh, 100);
+        }
+    }
+
+    function swap(selector, fresh) {
+        const current = document.querySelector(selector);
+        const replacement = fresh.querySelector(selector);
+        if (current && replacement) {
+            current.replaceWith(document.importNode(replacement, true));
+        }
+    }
+
+    async function refresh() {
+        const ids = pending;
+        pending = new Set();
+        timer = null;
+
+        let fresh;
+        try {
+            const response = await fetch(location.href, { cache: 'no-store' });
+            if (!response.ok) {
+                return;
+            }
+            fresh = new DOMParser().parseFromString(await response.text(), 'text/html');
+        } catch (e) {
+            return;
+        }
+
+        // a chore that came or went changes the layout, so redraw it all
+        const moved = Array.from(ids).some(function (id) {
+            const selector = '#chore-' + id;
+            return (document.querySelector(selector) === null) !== (fresh.querySelector(selector) === null);
+        });
+        if (ids.has('all') || moved) {
+            swap('main.home', fresh);
+        } else {
+            for (const id of ids) {
+                swap('#chore-' + id, fresh);
+            }
+        }
+        swap('footer .undo-redo', fresh);
+    }
+
+    const source = new EventSource('/updates');
+    let connected = false;
+    source.addEventListener('open', function () {
+        // anything could have happened while we were disconnected
+        if (connected) {
+            queue('all');
+        }
+        connected = true;
+    });
+    source.addEventListener('chore', function (event) {
+        queue(event.data);
+    });
+    source.addEventListener('chores', function () {
+        queue('all');
+    });
+
+    // the days since chores were last done still tick over with no updates
+    setInterval(function () {
+        queue('all');
+    }, 5 * 60 * 1000);
+})();
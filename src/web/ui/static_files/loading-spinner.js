@@ -1,25 +1,20 @@
 (function () {
-    const chore_forms = document.querySelectorAll('form.chore-form');
-    for (const form of chore_forms) {
-        form.addEventListener('submit', function (event) {
-            // remove all children from the form that don't have the spinner class
-            let nonSpinners = [];
-            let spinners = [];
-            for (const child of form.children) {
-                if (!child.classList.contains('spinner')) {
-                    nonSpinners.push(child);
-                }
-                else {
-                    spinners.push(child);
-                }
-            }
+    // listen on the document so that chores re-rendered by live updates get
+    // spinners too
+    document.addEventListener('submit', function (event) {
+        const form = event.target;
+        if (!form.classList.contains('chore-form')) {
+            return;
+        }
 
-            for (const child of nonSpinners) {
-                form.removeChild(child);
-            }
-            for (const spinner of spinners) {
-                spinner.classList.remove('hidden');
+        // hide everything but the spinner rather than removing it, since the
+        // form still has to be posted with its hidden CSRF token
+        for (const child of form.children) {
+            if (child.classList.contains('spinner')) {
+                child.classList.remove('hidden');
+            } else {
+                child.classList.add('hidden');
             }
-        });
-    }
+        }
+    });
 })();
@@ -75,7 +75,11 @@ pub async fn svg_icon(
         "new.svg" => include_str!("new.svg"),
         "save.svg" => include_str!("save.svg"),
         "trash.svg" => include_str!("trash.svg"),
-        _ => return Response::builder().status(404).body(Body::empty()).unwrap(),
+        "history.svg" => include_str!("history.svg"),
+        _ => match icon.strip_suffix(".svg").and_then(chore_icon) {
+            Some(icon_contents) => icon_contents,
+            None => return Response::builder().status(404).body(Body::empty()).unwrap(),
+        },
     };
 
     let etag = format!("{:x}", md5::compute(&icon));
@@ -97,6 +101,25 @@ pub async fn svg_icon(
         .expect("Can build icon response")
 }
 
+/// The built-in icons chores can use, by the names in
+/// [`validation::CHORE_ICONS`](crate::web::validation::CHORE_ICONS)
+fn chore_icon(name: &str) -> Option<&'static str> {
+    Some(match name {
+        "bath" => include_str!("chore-icons/bath.svg"),
+        "bed" => include_str!("chore-icons/bed.svg"),
+        "bin" => include_str!("chore-icons/bin.svg"),
+        "brush" => include_str!("chore-icons/brush.svg"),
+        "car" => include_str!("chore-icons/car.svg"),
+        "cart" => include_str!("chore-icons/cart.svg"),
+        "paw" => include_str!("chore-icons/paw.svg"),
+        "shirt" => include_str!("chore-icons/shirt.svg"),
+        "sparkles" => include_str!("chore-icons/sparkles.svg"),
+        "sprout" => include_str!("chore-icons/sprout.svg"),
+        "utensils" => include_str!("chore-icons/utensils.svg"),
+        _ => return None,
+    })
+}
+
 #[derive(Deserialize, Debug)]
 pub struct IconQuery {
     pub s: Option<u32>,
@@ -185,3 +208,30 @@ pub async fn manifest() -> impl IntoResponse {
         .body(Body::from(manifest))
         .expect("Can build manifest response")
 }
+
+/// The service worker, which has to be served from the root to control the
+/// whole app. Its cache is named after the build so that a new build
+/// replaces the cached shell
+pub async fn service_worker() -> impl IntoResponse {
+    let version = format!("{:x}", md5::compute(env!("BUILD_TIME_LAST_MODIFIED")));
+    let script = include_str!("service-worker.js").replace("{{VERSION}}", &version);
+    Response::builder()
+        .header("Content-Type", "text/javascript; charset=utf-8")
+        .header("Content-Length", script.len())
+        .header("Cache-Control", "no-cache")
+        .body(Body::from(script))
+        .expect("Can build service worker response")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::web::validation::CHORE_ICONS;
+
+    #[test]
+    fn every_chore_icon_is_served() {
+        for name in CHORE_ICONS {
+            assert!(chore_icon(name).is_some(), "{name} has no svg");
+        }
+    }
+}
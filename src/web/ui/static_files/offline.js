@@ -0,0 +1,24 @@
+(function () {
+    // install the service worker that keeps the app working offline, and
+    // have it send any presses it queued once we're back online
+    if (!('serviceWorker' in navigator)) {
+        return;
+    }
+    navigator.serviceWorker.register('/service-worker.js');
+
+    function replay() {
+        // presses queued before logging in again need this page's CSRF token
+        // rather than the one they were queued with
+        const field = document.querySelector('input[name="csrf_token"]');
+        navigator.serviceWorker.ready.then(function (registration) {
+            if (registration.active) {
+                registration.active.postMessage({
+                    replay: true,
+                    csrfToken: field ? field.value : null,
+                });
+            }
+        });
+    }
+    window.addEventListener('online', replay);
+    replay();
+})();
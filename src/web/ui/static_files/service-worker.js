@@ -0,0 +1,181 @@
+// keeps chordle usable when the connection drops: pages and styles are
+// served from a cache, and chore button presses are queued in IndexedDB
+// until they can be sent to `/events/{chore_id}/queued`
+
+// replaced with a hash of the build time when served
+const CACHE = 'chordle-{{VERSION}}';
+const SHELL = ['/', '/styles.css', '/manifest.json', '/icons/undo.svg', '/icons/redo.svg'];
+const PRESS = /^\/events\/(\d+)$/;
+
+self.addEventListener('install', function (event) {
+    event.waitUntil(caches.open(CACHE).then(function (cache) {
+        return cache.addAll(SHELL);
+    }));
+    self.skipWaiting();
+});
+
+self.addEventListener('activate', function (event) {
+    event.waitUntil(caches.keys().then(function (keys) {
+        return Promise.all(keys
+            .filter(function (key) { return key !== CACHE; })
+            .map(function (key) { return caches.delete(key); }));
+    }).then(function () {
+        return self.clients.claim();
+    }));
+});
+
+function openQueue() {
+    return new Promise(function (resolve, reject) {
+        const request = indexedDB.open('chordle', 1);
+        request.onupgradeneeded = function () {
+            request.result.createObjectStore('presses', { keyPath: 'key' });
+        };
+        request.onsuccess = function () { resolve(request.result); };
+        request.onerror = function () { reject(request.error); };
+    });
+}
+
+function withStore(mode, action) {
+    return openQueue().then(function (db) {
+        return new Promise(function (resolve, reject) {
+            const transaction = db.transaction('presses', mode);
+            const request = action(transaction.objectStore('presses'));
+            transaction.oncomplete = function () { resolve(request.result); };
+            transaction.onerror = function () { reject(transaction.error); };
+        });
+    });
+}
+
+async function queuePress(request, choreId) {
+    // the press is sent again with the CSRF token its page was given
+    const form = new URLSearchParams(await request.text());
+    await withStore('readwrite', function (store) {
+        return store.put({
+            key: self.crypto.randomUUID(),
+            choreId: choreId,
+            timestamp: new Date().toISOString(),
+            csrfToken: form.get('csrf_token') || request.headers.get('x-csrf-token') || '',
+        });
+    });
+    if (self.registration.sync) {
+        self.registration.sync.register('replay').catch(function () {});
+    }
+
+    if (request.mode === 'navigate') {
+        return Response.redirect('/', 303);
+    }
+    // tells the page the press was queued rather than recorded
+    return new Response(null, { status: 202 });
+}
+
+let replaying = null;
+
+// send queued presses oldest first, stopping at the first that can't be sent.
+// `csrfToken` is the token of a page that's open now, if there is one
+function replay(csrfToken) {
+    if (replaying) {
+        return replaying;
+    }
+    replaying = (async function () {
+        const presses = await withStore('readonly', function (store) {
+            return store.getAll();
+        });
+        presses.sort(function (a, b) { return a.timestamp.localeCompare(b.timestamp); });
+        for (const press of presses) {
+            const response = await fetch('/events/' + press.choreId + '/queued', {
+                method: 'POST',
+                body: new URLSearchParams({
+                    timestamp: press.timestamp,
+                    key: press.key,
+                    csrf_token: csrfToken || press.csrfToken || '',
+                }),
+            });
+            if (response.status >= 500 || response.redirected) {
+                // try again later, or once logged back in
+                break;
+            }
+            if (response.status === 403) {
+                // keep it until a page with the current CSRF token is open
+                continue;
+            }
+            // anything else won't go any better next time
+            await withStore('readwrite', function (store) {
+                return store.delete(press.key);
+            });
+        }
+    })().catch(function () {}).finally(function () {
+        replaying = null;
+    });
+    return replaying;
+}
+
+self.addEventListener('sync', function (event) {
+    if (event.tag === 'replay') {
+        event.waitUntil(replay());
+    }
+});
+
+self.addEventListener('message', function (event) {
+    if (event.data && event.data.replay) {
+        event.waitUntil(replay(event.data.csrfToken));
+    }
+});
+
+self.addEventListener('fetch', function (event) {
+    const request = event.request;
+    const url = new URL(request.url);
+    if (url.origin !== self.location.origin) {
+        return;
+    }
+
+    const press = url.pathname.match(PRESS);
+    if (request.method === 'POST' && press) {
+        const queued = request.clone();
+        event.respondWith(fetch(request).then(function (response) {
+            event.waitUntil(replay());
+            return response;
+        }, function () {
+            return queuePress(queued, press[1]);
+        }));
+        return;
+    }
+
+    if (request.method !== 'GET' || url.pathname === '/updates') {
+        return;
+    }
+
+    // pages are fetched fresh whenever possible, everything else the shell
+    // needs comes from the cache and is refreshed in the background
+    if (request.mode === 'navigate') {
+        event.respondWith(fetch(request).then(function (response) {
+            if (response.ok && !response.redirected && url.pathname === '/') {
+                const copy = response.clone();
+                caches.open(CACHE).then(function (cache) { cache.put('/', copy); });
+            }
+            return response;
+        }, function () {
+            return caches.match(request, { ignoreSearch: true }).then(function (cached) {
+                return cached || caches.match('/');
+            });
+        }));
+        return;
+    }
+
+    if (SHELL.includes(url.pathname)) {
+        event.respondWith(caches.open(CACHE).then(function (cache) {
+            return cache.match(request).then(function (cached) {
+                const fresh = fetch(request).then(function (response) {
+                    if (response.ok) {
+                        cache.put(request, response.clone());
+                    }
+                    return response;
+                });
+                if (cached) {
+                    fresh.catch(function () {});
+                    return cached;
+                }
+                return fresh;
+            });
+        }));
+    }
+});
@@ -1,17 +1,139 @@
-use axum::{extract::State, http::HeaderMap};
+use axum::{
+    extract::{Query, State, rejection::QueryRejection},
+    http::HeaderMap,
+};
 use axum_extra::extract::CookieJar;
 use color_eyre::eyre::Context;
+use jiff::{Span, Timestamp, Zoned};
 use maud::{Markup, html};
+use serde::Deserialize;
 
-use crate::{db::Chore, stats::ChoreStats, web::AppState};
+use crate::{
+    db::{Chore, Member},
+    stats::{ChoreStats, MemberStats},
+    web::AppState,
+};
 
-use super::{error::ErrorResponse, l10n::Lang};
+use super::{
+    STATS_URI,
+    chore::chore_uri,
+    error::ErrorResponse,
+    l10n::{L10N, Lang},
+};
+
+/// How far back the household view of the stats page looks
+#[derive(Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum StatsWindow {
+    Week,
+    #[default]
+    Month,
+    Quarter,
+    Year,
+    All,
+}
+
+impl StatsWindow {
+    const ALL: [StatsWindow; 5] = [
+        StatsWindow::Week,
+        StatsWindow::Month,
+        StatsWindow::Quarter,
+        StatsWindow::Year,
+        StatsWindow::All,
+    ];
+
+    fn as_str(self) -> &'static str {
+        match self {
+            StatsWindow::Week => "week",
+            StatsWindow::Month => "month",
+            StatsWindow::Quarter => "quarter",
+            StatsWindow::Year => "year",
+            StatsWindow::All => "all",
+        }
+    }
+
+    /// The start of the window, `None` if it covers all time
+    fn since(self, now: &Zoned) -> Option<Timestamp> {
+        let days = match self {
+            StatsWindow::Week => 7,
+            StatsWindow::Month => 30,
+            StatsWindow::Quarter => 90,
+            StatsWindow::Year => 365,
+            StatsWindow::All => return None,
+        };
+        Some(now.saturating_sub(Span::new().days(days)).timestamp())
+    }
+}
+
+#[derive(Deserialize, Default)]
+pub struct StatsQuery {
+    #[serde(default)]
+    window: StatsWindow,
+}
+
+fn render_household(
+    window: StatsWindow,
+    stats: &[MemberStats],
+    members: &[Member],
+    lang: Lang,
+    l10n: &L10N,
+) -> Markup {
+    let name = |stat: &MemberStats| match stat.member_id {
+        Some(member_id) => members
+            .iter()
+            .find(|member| member.id == member_id)
+            .map_or_else(|| member_id.to_string(), |member| member.name.clone()),
+        None => l10n.translate(lang, "nobody"),
+    };
+
+    html! {
+        h2 { (l10n.translate(lang, "household")) }
+        form.stats-window method="GET" action=(STATS_URI) {
+            select name="window" onchange="this.form.submit()" title=(l10n.translate(lang, "stats-window")) {
+                @for option in StatsWindow::ALL {
+                    option value=(option.as_str()) selected[option == window] {
+                        (l10n.translate(lang, format!("stats-window-{}", option.as_str())))
+                    }
+                }
+            }
+            noscript {
+                button type="submit" { (l10n.translate(lang, "save")) }
+            }
+        }
+        table {
+            thead {
+                tr {
+                    th { (l10n.translate(lang, "member")) }
+                    th { (l10n.translate(lang, "times-completed")) }
+                    th { (l10n.translate(lang, "share-of-completions")) }
+                    th { (l10n.translate(lang, "effort")) }
+                    th { (l10n.translate(lang, "on-time-rate")) }
+                }
+            }
+            tbody {
+                @for stat in stats {
+                    tr {
+                        td { (name(stat)) }
+                        td { (stat.num_completed) }
+                        td { (format!("{share:.0}%", share = stat.share_of_completions * 100.0)) }
+                        td { (format!("{effort} ({share:.0}%)", effort = stat.effort, share = stat.share_of_effort * 100.0)) }
+                        td { (format!("{rate:.0}%", rate = stat.on_time_rate * 100.0)) }
+                    }
+                }
+            }
+        }
+    }
+}
 
 pub async fn stats_page(
     State(app_state): State<AppState>,
     headers: HeaderMap,
     jar: CookieJar,
+    query: Result<Query<StatsQuery>, QueryRejection>,
 ) -> Result<Markup, ErrorResponse> {
+    // an unknown window falls back to the default rather than failing the page
+    let window = query.map(|Query(query)| query).unwrap_or_default().window;
+
     let accept_language = headers
         .get("accept-language")
         .and_then(|value| value.to_str().ok());
@@ -40,12 +162,31 @@ pub async fn stats_page(
             .then_with(|| a.0.name.cmp(&b.0.name))
     });
 
+    let members = app_state
+        .db
+        .get_members()
+        .await
+        .wrap_err("Failed to get members for stats page")?;
+    let mut household =
+        crate::stats::get_household_stats(&app_state.db, window.since(&Zoned::now()))
+            .await
+            .wrap_err("Failed to get household stats")?;
+    household.sort_by(|a, b| {
+        b.effort
+            .cmp(&a.effort)
+            .then_with(|| b.num_completed.cmp(&a.num_completed))
+    });
+
     Ok(super::template::page(
         lang,
         "Stats",
         html! {
             main.stats {
                 h1 { (app_state.l10n.translate(lang, "stats")) }
+                @if !members.is_empty() {
+                    (render_household(window, &household, &members, lang, &app_state.l10n))
+                    h2 { (app_state.l10n.translate(lang, "chores")) }
+                }
                 table {
                     thead {
                         tr {
@@ -58,7 +199,7 @@ pub async fn stats_page(
                     tbody {
                         @for stat in stats.iter() {
                             tr {
-                                td { (stat.0.name) }
+                                td { a href=(chore_uri(stat.0.id)) { (stat.0.name) } }
                                 td { (stat.1.num_completed) }
                                 td { (stat.1.num_overdue) }
                                 td { (format!("{mean:.1} ± {var:.2}", mean=stat.1.mean_overdue_days, var=stat.1.variance_overdue_days.sqrt())) }
@@ -0,0 +1,23 @@
+use tokio::sync::broadcast;
+
+use crate::db::ChoreId;
+
+/// How many updates a slow listener can fall behind before it misses some
+const CAPACITY: usize = 64;
+
+/// Something changed that pages showing chores should catch up on
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Update {
+    /// A single chore was done, postponed or edited, or had an event undone
+    /// or redone
+    Chore(ChoreId),
+    /// Chores were added or removed, or their due dates all moved
+    Chores,
+}
+
+/// Fans updates out to every open page, see [`AppState::notify`]
+///
+/// [`AppState::notify`]: super::AppState::notify
+pub fn channel() -> broadcast::Sender<Update> {
+    broadcast::channel(CAPACITY).0
+}
@@ -0,0 +1,127 @@
+use jiff::Span;
+
+use crate::schedule::Schedule;
+
+/// The maximum length of a chore name, in bytes
+pub const MAX_CHORE_NAME_LEN: usize = 160;
+
+/// Chore names must not be empty and must be at most [`MAX_CHORE_NAME_LEN`] long
+pub fn is_valid_chore_name(name: &str) -> bool {
+    !name.is_empty() && name.len() <= MAX_CHORE_NAME_LEN
+}
+
+/// The maximum length of a username, in bytes
+pub const MAX_USERNAME_LEN: usize = 64;
+
+/// Usernames must not be blank and must be at most [`MAX_USERNAME_LEN`] long
+pub fn is_valid_username(username: &str) -> bool {
+    !username.trim().is_empty() && username.len() <= MAX_USERNAME_LEN
+}
+
+/// The maximum length of an API token's name, in bytes
+pub const MAX_TOKEN_NAME_LEN: usize = 80;
+
+/// API token names must not be blank and must be at most
+/// [`MAX_TOKEN_NAME_LEN`] long
+pub fn is_valid_token_name(name: &str) -> bool {
+    !name.trim().is_empty() && name.len() <= MAX_TOKEN_NAME_LEN
+}
+
+/// Parse a chore interval in ISO8601 or "friendly" format, returning `None`
+/// if it can't be parsed
+pub fn parse_interval(interval: &str) -> Option<Span> {
+    interval.parse().ok()
+}
+
+/// Parse a chore schedule, returning `None` if it can't be parsed or never
+/// falls due. Empty schedules are rolling schedules
+pub fn parse_schedule(schedule: &str) -> Option<Schedule> {
+    schedule
+        .parse()
+        .ok()
+        .filter(|schedule: &Schedule| schedule.has_occurrences())
+}
+
+/// The most effort a single chore can be worth
+pub const MAX_EFFORT: u32 = 10;
+
+/// Parse how much effort a chore is, returning `None` if it isn't a whole
+/// number between 1 and [`MAX_EFFORT`]
+pub fn parse_effort(effort: &str) -> Option<u32> {
+    effort
+        .trim()
+        .parse()
+        .ok()
+        .filter(|effort| (1..=MAX_EFFORT).contains(effort))
+}
+
+/// The maximum length of a room name, in bytes
+pub const MAX_ROOM_LEN: usize = 80;
+
+/// Parse the room a chore is in, returning `Some(None)` for a blank room and
+/// `None` if the name is longer than [`MAX_ROOM_LEN`]
+pub fn parse_room(room: &str) -> Option<Option<String>> {
+    let room = room.trim();
+    if room.len() > MAX_ROOM_LEN {
+        None
+    } else if room.is_empty() {
+        Some(None)
+    } else {
+        Some(Some(room.to_string()))
+    }
+}
+
+/// The built-in icons a chore can show instead of an emoji
+pub const CHORE_ICONS: [&str; 11] = [
+    "bath", "bed", "bin", "brush", "car", "cart", "paw", "shirt", "sparkles", "sprout", "utensils",
+];
+
+/// The most characters an emoji icon can be made of, enough for joined
+/// sequences like 🧑‍🍳
+pub const MAX_EMOJI_CHARS: usize = 8;
+
+/// Parse a chore's icon, which is either one of [`CHORE_ICONS`] or an emoji.
+/// Returns `Some(None)` for no icon and `None` if the icon is invalid
+pub fn parse_icon(icon: &str) -> Option<Option<String>> {
+    let icon = icon.trim();
+    let is_emoji = !icon.chars().any(|c| c.is_ascii()) && icon.chars().count() <= MAX_EMOJI_CHARS;
+    if icon.is_empty() {
+        Some(None)
+    } else if CHORE_ICONS.contains(&icon) || is_emoji {
+        Some(Some(icon.to_string()))
+    } else {
+        None
+    }
+}
+
+/// Parse a chore's accent colour in `#rrggbb` form, returning `Some(None)` for
+/// no colour and `None` if the colour is invalid
+pub fn parse_colour(colour: &str) -> Option<Option<String>> {
+    let colour = colour.trim();
+    if colour.is_empty() {
+        return Some(None);
+    }
+    let hex = colour.strip_prefix('#')?;
+    if hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        Some(Some(colour.to_ascii_lowercase()))
+    } else {
+        None
+    }
+}
+
+/// The maximum length of a member's name, in bytes
+pub const MAX_MEMBER_NAME_LEN: usize = 64;
+
+/// Member names must not be blank and must be at most
+/// [`MAX_MEMBER_NAME_LEN`] long
+pub fn is_valid_member_name(name: &str) -> bool {
+    !name.trim().is_empty() && name.len() <= MAX_MEMBER_NAME_LEN
+}
+
+/// The maximum length of an idempotency key, in bytes
+pub const MAX_IDEMPOTENCY_KEY_LEN: usize = 128;
+
+/// Idempotency keys are chosen by clients, so only their length is checked
+pub fn is_valid_idempotency_key(key: &str) -> bool {
+    !key.is_empty() && key.len() <= MAX_IDEMPOTENCY_KEY_LEN
+}